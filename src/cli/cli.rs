@@ -33,6 +33,10 @@ pub struct Cli {
     #[arg(long)]
     pub r#loop: bool,
 
+    /// Run a script in every workspace package that defines it, in dependency order
+    #[arg(long, value_name = "SCRIPT")]
+    pub all_packages: Option<String>,
+
     /// Command to execute (run, dev, test, etc)
     pub script_command: Option<String>,
 
@@ -59,6 +63,8 @@ pub enum Commands {
         #[command(subcommand)]
         action: ProjectsAction,
     },
+    /// Explain problems with the project's configuration, such as conflicting lock files
+    Doctor,
 }
 
 #[derive(Parser)]
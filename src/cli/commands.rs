@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use crate::cli::{Cli, Commands, ProjectsAction};
 use crate::config::Settings;
 use crate::execution::{run_script, run_script_with_env};
+use crate::package_managers::dependency_order;
 use crate::types::{find_synonym_script, Project, Script, SPECIAL_SCRIPTS};
 use crate::themes::Theme;
 use crate::tui::run_tui;
@@ -12,15 +13,38 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode},
 };
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
 impl Commands {
-    pub fn execute(&self) -> Result<()> {
+    pub fn execute(&self, dir: &Path, settings: &Settings) -> Result<()> {
         match self {
             Commands::Projects { action } => action.execute(),
+            Commands::Doctor => doctor(dir, settings),
         }
     }
 }
 
+fn doctor(dir: &Path, settings: &Settings) -> Result<()> {
+    let project = Project::detect(dir)
+        .ok_or_else(|| anyhow::anyhow!("Could not detect package manager"))?
+        .with_priority(&settings.provider_priority);
+    println!("Project: {}", project.path.display());
+    if let Some(tool) = project.tool_description() {
+        println!("Package manager: {}", tool);
+    }
+
+    let warnings = project.warnings();
+    if warnings.is_empty() {
+        println!("No problems found.");
+    }
+    for warning in warnings {
+        println!();
+        println!("Warning: {}", warning.message);
+        println!("  {}", warning.explanation);
+    }
+    Ok(())
+}
+
 impl ProjectsAction {
     pub fn execute(&self) -> Result<()> {
         let mut settings = Settings::new()?;
@@ -50,23 +74,12 @@ impl ProjectsAction {
 
 impl Cli {
     pub fn execute(self) -> Result<()> {
-        if let Some(command) = self.command {
-            return command.execute();
-        }
-
         let settings = Settings::new()?;
+        let working_dir = self.working_dir(&settings)?;
 
-        // Determine working directory
-        let working_dir = if let Some(project) = &self.project {
-            settings
-                .get_project_path(project)
-                .cloned()
-                .ok_or_else(|| anyhow::anyhow!("Project '{}' not found", project))?
-        } else {
-            self.dir
-                .clone()
-                .unwrap_or_else(|| std::env::current_dir().unwrap())
-        };
+        if let Some(command) = &self.command {
+            return command.execute(&working_dir, &settings);
+        }
 
         // Change to working directory
         std::env::set_current_dir(&working_dir)?;
@@ -74,7 +87,16 @@ impl Cli {
         // Detect package manager
         let current_dir = std::env::current_dir()?;
         let project = Project::detect(&current_dir)
-            .ok_or_else(|| anyhow::anyhow!("Could not detect package manager"))?;
+            .ok_or_else(|| anyhow::anyhow!("Could not detect package manager"))?
+            .with_priority(&settings.provider_priority);
+        for warning in project.warnings() {
+            eprintln!("Warning: {}. Run `psr doctor` for details.", warning.message);
+        }
+
+        if let Some(script_name) = &self.all_packages {
+            let exit_code = self.run_in_all_packages(&project, script_name)?;
+            std::process::exit(exit_code);
+        }
 
         // Find scripts
         let scripts = project.scripts()?;
@@ -97,6 +119,22 @@ impl Cli {
         self.run_interactive_mode(&project)
     }
 
+    /// Returns the directory of the saved project named by `--project`, or
+    /// else the `--dir` directory or the current directory.
+    fn working_dir(&self, settings: &Settings) -> Result<PathBuf> {
+        if let Some(project) = &self.project {
+            settings
+                .get_project_path(project)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Project '{}' not found", project))
+        } else {
+            Ok(self
+                .dir
+                .clone()
+                .unwrap_or_else(|| std::env::current_dir().unwrap()))
+        }
+    }
+
     fn handle_list_flag(&self, scripts: &[Script]) -> Result<()> {
         println!("Available scripts:");
         for script in scripts {
@@ -129,7 +167,7 @@ impl Cli {
             }
             "run" => {
                 if let Some(script_name) = &self.script {
-                    if let Some(script) = scripts.iter().find(|s| &s.name == script_name) {
+                    if let Some(script) = scripts.iter().find(|s| s.is_named(script_name)) {
                         script.name.clone()
                     } else {
                         anyhow::bail!("Script '{}' not found", script_name);
@@ -155,12 +193,74 @@ impl Cli {
             env_vars.insert("NODE_ENV".to_string(), "dev".to_string());
         }
 
-        run_script_with_env(
-            &project.package_manager,
-            &script_to_run,
-            &self.args,
-            &env_vars,
-        )
+        let script = scripts
+            .iter()
+            .find(|s| s.name == script_to_run)
+            .ok_or_else(|| anyhow::anyhow!("Script '{}' not found", script_to_run))?;
+        run_script_with_env(project.script_command(script), &self.args, &env_vars)
+    }
+
+    /// Runs `script_name` in each workspace member that defines it, members
+    /// first in dependency order, and prints a summary of the results.
+    fn run_in_all_packages(&self, project: &Project, script_name: &str) -> Result<i32> {
+        let members: Vec<_> = project
+            .package_managers
+            .iter()
+            .flat_map(|package_manager| package_manager.workspace_members(&project.path))
+            .collect();
+        if members.is_empty() {
+            anyhow::bail!("{} is not a workspace", project.path.display());
+        }
+
+        let mut results: Vec<(String, Result<i32>)> = Vec::new();
+        for member in dependency_order(members) {
+            // A member whose scripts can't be read fails without stopping
+            // the others
+            let scripts = match member.package_manager.find_scripts(&member.path) {
+                Ok(scripts) => scripts,
+                Err(error) => {
+                    eprintln!("==> {}: {}", member.name, error);
+                    results.push((member.name, Err(error)));
+                    continue;
+                }
+            };
+            let Some(script) = scripts.iter().find(|s| s.is_named(script_name)) else {
+                continue;
+            };
+            println!("==> {} ({})", member.name, member.path.display());
+            let mut command = member.package_manager.script_command(script);
+            command.current_dir(&member.path);
+            results.push((member.name, run_script(command, &self.args)));
+        }
+
+        if results.is_empty() {
+            anyhow::bail!("No workspace package defines script '{}'", script_name);
+        }
+
+        let width = results
+            .iter()
+            .map(|(name, _)| name.len())
+            .chain(["Package".len()])
+            .max()
+            .unwrap_or(0);
+        println!();
+        println!("{:width$}  Result", "Package", width = width);
+        for (name, result) in &results {
+            let result = match result {
+                Ok(0) => "passed".to_string(),
+                Ok(exit_code) => format!("failed (exit code {})", exit_code),
+                Err(error) => format!("failed ({})", error),
+            };
+            println!("{:width$}  {}", name, result, width = width);
+        }
+
+        let failures = results
+            .iter()
+            .filter(|(_, result)| !matches!(result, Ok(0)))
+            .count();
+        println!();
+        println!("{} passed, {} failed", results.len() - failures, failures);
+        Ok(if failures == 0 { 0 } else { 1 })
     }
 
     fn run_interactive_mode(&self, project: &Project) -> Result<()> {
@@ -182,7 +282,11 @@ impl Cli {
                             mode = Mode::TUI;
                             continue;
                         }
-                        let exit_code = run_script(&project.package_manager, &script, &[])?;
+                        let script = scripts
+                            .iter()
+                            .find(|s| s.name == script)
+                            .ok_or_else(|| anyhow::anyhow!("Script '{}' not found", script))?;
+                        let exit_code = run_script(project.script_command(script), &[])?;
                         std::process::exit(exit_code);
                     }
                     break;
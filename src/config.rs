@@ -15,6 +15,11 @@ pub struct Settings {
     pub projects: HashMap<String, PathBuf>,
     #[serde(default = "default_show_emoji")]
     pub show_emoji: bool,
+    /// The tools whose scripts come first in projects with several
+    /// providers, such as `["cargo", "npm"]`, which also decides which
+    /// script a shared name such as `test` runs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub provider_priority: Vec<String>,
 }
 
 impl Default for Theme {
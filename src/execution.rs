@@ -1,25 +1,20 @@
 use std::collections::HashMap;
+use std::process::Command;
 use anyhow::Result;
 
 use anyhow::Context;
 
-use crate::package_managers::PackageManager;
-
-pub fn run_script(
-    package_manager: &Box<dyn PackageManager>,
-    script: &str,
-    args: &[String],
-) -> Result<i32> {
-    run_script_with_env(package_manager, script, args, &HashMap::new())
+/// Runs a script's `command`, as returned by `Project::script_command` or
+/// `PackageManager::script_command`.
+pub fn run_script(command: Command, args: &[String]) -> Result<i32> {
+    run_script_with_env(command, args, &HashMap::new())
 }
 
 pub fn run_script_with_env(
-    package_manager: &Box<dyn PackageManager>,
-    script: &str,
+    mut command: Command,
     args: &[String],
     env_vars: &HashMap<String, String>,
 ) -> Result<i32> {
-    let mut command = package_manager.run_command(script);
     command.args(args);
     command.envs(env_vars);
 
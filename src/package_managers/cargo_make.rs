@@ -0,0 +1,82 @@
+use anyhow::Result;
+use toml::Value;
+
+use std::{fs, path::Path};
+
+use crate::types::{Script, ScriptType};
+
+/// Returns the tasks defined in a cargo-make `Makefile.toml` in `dir`, run
+/// via `cargo make <task>`. Private tasks are left out.
+pub(super) fn find_cargo_make_tasks(dir: &Path) -> Result<Vec<Script>> {
+    let Ok(content) = fs::read_to_string(dir.join("Makefile.toml")) else {
+        return Ok(Vec::new());
+    };
+    let makefile: Value = toml::from_str(&content)?;
+
+    let mut scripts = Vec::new();
+    let tasks = makefile.get("tasks").and_then(|t| t.as_table());
+    for (name, task) in tasks.into_iter().flatten() {
+        if task.get("private").and_then(|p| p.as_bool()) == Some(true) {
+            continue;
+        }
+        let description = task
+            .get("description")
+            .and_then(|d| d.as_str())
+            .map(|d| d.to_string());
+        let script_type = task
+            .get("category")
+            .and_then(|c| c.as_str())
+            .and_then(category_script_type)
+            .unwrap_or_else(|| ScriptType::from_script(name, &task_commands(task)));
+        scripts.push(Script::new(
+            name,
+            &format!("cargo make {}", name),
+            description,
+            Some(script_type),
+            None,
+        ));
+    }
+
+    Ok(scripts)
+}
+
+/// Maps a cargo-make task category, such as the `Build` and `Test`
+/// categories of its built-in tasks, onto a script type.
+fn category_script_type(category: &str) -> Option<ScriptType> {
+    match category.to_lowercase().as_str() {
+        "build" => Some(ScriptType::Build),
+        "test" => Some(ScriptType::Test),
+        "development" | "dev" => Some(ScriptType::Serve),
+        "lint" => Some(ScriptType::Lint),
+        "format" => Some(ScriptType::Format),
+        "cleanup" | "clean" => Some(ScriptType::Clean),
+        "publish" => Some(ScriptType::Publish),
+        "deploy" => Some(ScriptType::Deploy),
+        _ => None,
+    }
+}
+
+/// Returns the command, arguments and script of a task, for classification.
+fn task_commands(task: &Value) -> String {
+    let strings = |value: Option<&Value>| -> Vec<String> {
+        match value {
+            Some(Value::String(s)) => vec![s.clone()],
+            Some(Value::Array(items)) => items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .map(|item| item.to_string())
+                .collect(),
+            // Scripts may also be `{ file = "..." }` or `{ main = [...] }`
+            Some(Value::Table(table)) => table
+                .values()
+                .filter_map(|v| v.as_str())
+                .map(|v| v.to_string())
+                .collect(),
+            _ => Vec::new(),
+        }
+    };
+    let mut commands = strings(task.get("command"));
+    commands.extend(strings(task.get("args")));
+    commands.extend(strings(task.get("script")));
+    commands.join(" ")
+}
@@ -0,0 +1,133 @@
+use anyhow::Result;
+use serde::Deserialize;
+
+use std::{fs, path::Path, process::Command};
+
+use super::{shell_command, PackageManager};
+use crate::types::{Script, ScriptType};
+
+/// The presets files, shared and per-user, in the order CMake reads them.
+const PRESETS_FILES: &[&str] = &["CMakePresets.json", "CMakeUserPresets.json"];
+
+/// The kinds of preset, with the prefix of their script names and the
+/// command that runs them.
+const PRESET_KINDS: &[(&str, &str, ScriptType)] = &[
+    ("configure", "cmake --preset", ScriptType::Build),
+    ("build", "cmake --build --preset", ScriptType::Build),
+    ("test", "ctest --preset", ScriptType::Test),
+];
+
+pub struct CMakePackageManager;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CMakePresets {
+    #[serde(default)]
+    configure_presets: Vec<Preset>,
+    #[serde(default)]
+    build_presets: Vec<Preset>,
+    #[serde(default)]
+    test_presets: Vec<Preset>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Preset {
+    name: String,
+    display_name: Option<String>,
+    description: Option<String>,
+    /// Hidden presets only serve as bases for other presets.
+    #[serde(default)]
+    hidden: bool,
+}
+
+impl PackageManager for CMakePackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("CMakePresets.json").exists() {
+            Some(CMakePackageManager)
+        } else {
+            None
+        }
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "cmake"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("cmake");
+        cmd.arg("--preset").arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let mut scripts = Vec::new();
+        for file in PRESETS_FILES {
+            let Ok(content) = fs::read_to_string(path.join(file)) else {
+                continue;
+            };
+            let presets: CMakePresets = serde_json::from_str(&content)?;
+            let presets = [
+                presets.configure_presets,
+                presets.build_presets,
+                presets.test_presets,
+            ];
+            for (&(kind, command, script_type), presets) in PRESET_KINDS.iter().zip(presets) {
+                for preset in presets.into_iter().filter(|preset| !preset.hidden) {
+                    scripts.push(Script::new(
+                        &format!("{}:{}", kind, preset.name),
+                        &format!("{} {}", command, preset.name),
+                        preset.description.or(preset.display_name),
+                        Some(script_type),
+                        None,
+                    ));
+                }
+            }
+        }
+        Ok(scripts)
+    }
+
+    // Build and test presets run through `cmake --build` and `ctest`, so each
+    // script's own command is what runs it.
+    fn script_command(&self, script: &Script) -> Command {
+        shell_command(&script.command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir =
+            create_cmake_presets_project(&std::env::temp_dir().join("cmake-project")).unwrap();
+        let cmake = CMakePackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = cmake.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            [
+                "configure:debug",
+                "configure:release",
+                "build:debug",
+                "test:debug",
+                "configure:local"
+            ]
+        );
+        assert!(scripts.iter().any(|s| s.name == "configure:debug"
+            && s.command == "cmake --preset debug"
+            && s.description.as_deref() == Some("Debug build with sanitizers")));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "configure:release" && s.description.as_deref() == Some("Release")));
+        assert!(scripts.iter().any(|s| s.name == "build:debug"
+            && s.command == "cmake --build --preset debug"
+            && s.script_type == ScriptType::Build));
+        assert!(scripts.iter().any(|s| s.name == "test:debug"
+            && s.command == "ctest --preset debug"
+            && s.script_type == ScriptType::Test));
+    }
+}
@@ -0,0 +1,102 @@
+use std::path::Path;
+
+/// A problem with a project's configuration that psr works around but that
+/// the user should know about.
+pub struct Warning {
+    /// A one-line summary, shown when listing or running scripts.
+    pub message: String,
+    /// A longer explanation and suggested fix, shown by `psr doctor`.
+    pub explanation: String,
+}
+
+/// Warns when `dir` holds the lock files of more than one tool. `lock_files`
+/// pairs each lock file name with its tool, `chosen` is the tool psr runs
+/// scripts with, and `reason` says why psr chose it.
+pub(super) fn lock_file_conflict(
+    dir: &Path,
+    lock_files: &[(&str, &str)],
+    chosen: &str,
+    reason: &str,
+) -> Option<Warning> {
+    let found: Vec<&(&str, &str)> = lock_files
+        .iter()
+        .filter(|(file, _)| dir.join(file).exists())
+        .collect();
+    let mut tools: Vec<&str> = found.iter().map(|(_, tool)| *tool).collect();
+    tools.dedup();
+    if tools.len() < 2 {
+        return None;
+    }
+
+    let files: Vec<&str> = found.iter().map(|(file, _)| *file).collect();
+    let (used, unused): (Vec<&str>, Vec<&str>) = files
+        .iter()
+        .partition(|file| found.iter().any(|(f, tool)| f == *file && *tool == chosen));
+    let fix = if used.is_empty() {
+        "Delete the lock files that are no longer in use.".to_string()
+    } else {
+        format!(
+            "If the project uses {}, delete the others (`rm {}`); otherwise, delete {}.",
+            chosen,
+            unused.join(" "),
+            used.join(" ")
+        )
+    };
+    Some(Warning {
+        message: format!(
+            "Conflicting lock files {}; using {}",
+            files.join(", "),
+            chosen
+        ),
+        explanation: format!(
+            "{}. Each tool reads and updates only its own lock file, so installing \
+             with another tool can resolve different dependency versions. {}",
+            reason, fix
+        ),
+    })
+}
+
+/// Warns that `source`, such as a configuration file, couldn't be parsed, so
+/// the tasks it defines aren't listed.
+pub fn unparsable_config(source: &str, error: &anyhow::Error) -> Warning {
+    Warning {
+        message: format!("Couldn't parse {}; its tasks aren't listed", source),
+        explanation: format!(
+            "{}: {}. psr lists the project's other scripts; fix the file to list its tasks too.",
+            source, error
+        ),
+    }
+}
+
+/// Warns that `makefile` includes `include`, a file that doesn't exist, so
+/// the targets it defines aren't listed.
+pub(super) fn missing_include(makefile: &Path, include: &Path) -> Warning {
+    Warning {
+        message: format!(
+            "{} includes missing file {}; its targets aren't listed",
+            makefile.file_name().unwrap_or_default().to_string_lossy(),
+            include.display()
+        ),
+        explanation: format!(
+            "make stops with an error unless a rule creates {0}. Create the file, \
+             or change `include {0}` to `-include {0}` if it is optional.",
+            include.display()
+        ),
+    }
+}
+
+/// Warns that the script `name` of the workspace member `member` isn't
+/// listed because a script of the workspace root has the same name.
+pub(super) fn shadowed_member_script(name: &str, member: &str) -> Warning {
+    Warning {
+        message: format!(
+            "The {} script of the {} crate is hidden by a root script of the same name",
+            name, member
+        ),
+        explanation: format!(
+            "The workspace root defines a script named {}, such as a cargo-make task or \
+             an alias, which takes the place of the member's. Rename one of them to list both.",
+            name
+        ),
+    }
+}
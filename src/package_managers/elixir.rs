@@ -0,0 +1,312 @@
+use anyhow::Result;
+
+use std::{fs, path::Path, process::Command};
+
+use super::PackageManager;
+use crate::types::{Script, ScriptType};
+
+pub struct ElixirPackageManager;
+
+/// An entry of the `aliases` keyword list of mix.exs, such as
+/// `setup: ["deps.get", "ecto.setup"]`.
+#[derive(Debug)]
+struct MixAlias {
+    name: String,
+    steps: Vec<String>,
+}
+
+impl PackageManager for ElixirPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("mix.exs").exists() {
+            Some(ElixirPackageManager)
+        } else {
+            None
+        }
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "mix"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("mix");
+        cmd.arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let content = strip_comments(&fs::read_to_string(path.join("mix.exs"))?);
+
+        let mut scripts = vec![
+            Script::new(
+                "compile",
+                "mix compile",
+                Some("Compile the project".to_string()),
+                Some(ScriptType::Build),
+                Some('b'),
+            ),
+            Script::new(
+                "test",
+                "mix test",
+                Some("Run the test suite".to_string()),
+                Some(ScriptType::Test),
+                Some('t'),
+            ),
+            Script::new(
+                "format",
+                "mix format",
+                Some("Format the source code".to_string()),
+                Some(ScriptType::Format),
+                Some('f'),
+            ),
+            Script::new(
+                "deps.get",
+                "mix deps.get",
+                Some("Fetch the project's dependencies".to_string()),
+                Some(ScriptType::Install),
+                None,
+            ),
+        ];
+        if has_dependency(&content, "phoenix") {
+            scripts.push(Script::new(
+                "phx.server",
+                "mix phx.server",
+                Some("Start the Phoenix server".to_string()),
+                Some(ScriptType::Serve),
+                Some('s'),
+            ));
+        }
+
+        for alias in parse_aliases(&content) {
+            let steps = alias.steps.join(", ");
+            let script = Script::new(
+                &alias.name,
+                &format!("mix {}", alias.name),
+                Some(format!("Alias for {}", steps)),
+                Some(detect_script_type(&alias.name, &steps)),
+                None,
+            );
+            // An alias named after a standard task replaces it
+            match scripts.iter_mut().find(|s| s.name == alias.name) {
+                Some(existing) => {
+                    *existing = Script {
+                        shortcut: existing.shortcut,
+                        ..script
+                    }
+                }
+                None => scripts.push(script),
+            }
+        }
+
+        Ok(scripts)
+    }
+}
+
+/// Classifies a task or alias by its name, or else by the Mix tasks it runs.
+fn detect_script_type(name: &str, steps: &str) -> ScriptType {
+    const PATTERNS: &[(&[&str], ScriptType)] = &[
+        (&["phx.server", "run --no-halt"], ScriptType::Serve),
+        (
+            &["ecto.migrate", "ecto.setup", "ecto.reset"],
+            ScriptType::Migration,
+        ),
+        (&["dialyzer"], ScriptType::TypeCheck),
+        (&["credo", "sobelow"], ScriptType::Lint),
+        (&["deps.get"], ScriptType::Install),
+        (&["phx.digest", "release"], ScriptType::Publish),
+    ];
+
+    let find_pattern = |text: &str| {
+        PATTERNS
+            .iter()
+            .find(|(patterns, _)| patterns.iter().any(|p| text.contains(p)))
+            .map(|(_, script_type)| *script_type)
+    };
+    find_pattern(name)
+        .or_else(|| Some(ScriptType::from_script(name, "")).filter(|t| *t != ScriptType::Other))
+        .or_else(|| find_pattern(steps))
+        .unwrap_or_else(|| ScriptType::from_script(name, steps))
+}
+
+/// Returns true if the `deps` of mix.exs include `{:<name>, ...}`.
+fn has_dependency(content: &str, name: &str) -> bool {
+    let compact: String = content.split_whitespace().collect();
+    compact.contains(&format!("{{:{},", name))
+}
+
+/// Finds the `aliases` keyword list of the project definition, given either
+/// inline or, as `mix new` and `mix phx.new` generate it, by a private
+/// function such as `aliases()`.
+fn parse_aliases(content: &str) -> Vec<MixAlias> {
+    let Some(start) = content.find("aliases:") else {
+        return Vec::new();
+    };
+    let value = content[start + "aliases:".len()..].trim_start();
+    let list = if value.starts_with('[') {
+        bracketed(value)
+    } else {
+        let function = value
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .next()
+            .unwrap_or_default();
+        ["defp", "def"]
+            .iter()
+            .find_map(|def| content.find(&format!("{} {} do", def, function)))
+            .and_then(|i| content[i..].find('[').map(|j| i + j))
+            .and_then(|i| bracketed(&content[i..]))
+    };
+    let Some(list) = list else {
+        return Vec::new();
+    };
+
+    split_top_level(list)
+        .into_iter()
+        .filter_map(|entry| {
+            let (name, value) = keyword(entry)?;
+            let steps = if value.starts_with('[') {
+                split_top_level(bracketed(value)?)
+                    .into_iter()
+                    .map(|step| unquote(step).to_string())
+                    .collect()
+            } else {
+                vec![unquote(value).to_string()]
+            };
+            Some(MixAlias { name, steps })
+        })
+        .collect()
+}
+
+/// Splits a keyword list entry such as `test: [...]` or `"ecto.setup": [...]`
+/// into its key and value.
+fn keyword(entry: &str) -> Option<(String, &str)> {
+    let entry = entry.trim();
+    let (name, rest) = if let Some(quoted) = entry.strip_prefix('"') {
+        let end = quoted.find('"')?;
+        (&quoted[..end], quoted[end + 1..].strip_prefix(':')?)
+    } else {
+        entry.split_once(": ")?
+    };
+    Some((name.to_string(), rest.trim()))
+}
+
+fn unquote(text: &str) -> &str {
+    let text = text.trim();
+    text.strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .unwrap_or(text)
+}
+
+/// Returns the contents of the bracketed list at the start of `text`.
+fn bracketed(text: &str) -> Option<&str> {
+    let mut depth = 0;
+    let mut in_string = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '[' | '(' | '{' if !in_string => depth += 1,
+            ']' | ')' | '}' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[1..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits a list's contents on the commas that separate its elements.
+fn split_top_level(list: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '[' | '(' | '{' if !in_string => depth += 1,
+            ']' | ')' | '}' if !in_string => depth -= 1,
+            ',' if !in_string && depth == 0 => {
+                items.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(list[start..].trim());
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+/// Removes `#` comments, leaving `#` inside strings, as in `"#{name}"`.
+fn strip_comments(content: &str) -> String {
+    content
+        .lines()
+        .map(|line| {
+            let mut in_string = false;
+            for (i, c) in line.char_indices() {
+                match c {
+                    '"' => in_string = !in_string,
+                    '#' if !in_string => return &line[..i],
+                    _ => {}
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir = create_mix_project(&std::env::temp_dir().join("mix-project")).unwrap();
+        let mix = ElixirPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = mix.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            [
+                "compile",
+                "test",
+                "format",
+                "deps.get",
+                "phx.server",
+                "setup",
+                "ecto.setup",
+                "ecto.reset",
+                "assets.deploy",
+                "lint"
+            ]
+        );
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "mix test"
+            && s.shortcut == Some('t')
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref()
+                == Some("Alias for ecto.create --quiet, ecto.migrate --quiet, test")));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "phx.server" && s.script_type == ScriptType::Serve));
+        assert!(scripts.iter().any(|s| s.name == "ecto.setup"
+            && s.script_type == ScriptType::Migration
+            && s.description.as_deref()
+                == Some("Alias for ecto.create, ecto.migrate, run priv/repo/seeds.exs")));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "assets.deploy" && s.script_type == ScriptType::Deploy));
+        assert!(scripts.iter().any(|s| s.name == "lint"
+            && s.script_type == ScriptType::Lint
+            && s.description.as_deref() == Some("Alias for credo --strict")));
+
+        let command = mix.script_command(&scripts[5]);
+        assert_eq!(command.get_program(), "mix");
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["setup"]);
+    }
+}
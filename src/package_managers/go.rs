@@ -1,8 +1,11 @@
 use anyhow::Result;
 
+use walkdir::WalkDir;
+
 use std::{fs, path::Path, process::Command};
 
-use super::PackageManager;
+use super::mage::{find_magefiles, parse_mage_targets};
+use super::{shell_command, PackageManager};
 use crate::types::{Script, ScriptType};
 
 pub struct GoPackageManager;
@@ -16,6 +19,10 @@ impl PackageManager for GoPackageManager {
         }
     }
 
+    fn tool_name(&self) -> &'static str {
+        "go"
+    }
+
     fn run_command(&self, script: &str) -> Command {
         let mut cmd = Command::new("go");
         cmd.arg(script);
@@ -78,27 +85,214 @@ impl PackageManager for GoPackageManager {
             ),
         ]);
 
-        // Try to parse Makefile targets if present
-        if path.join("Makefile").exists() {
-            if let Ok(content) = fs::read_to_string(path.join("Makefile")) {
-                for line in content.lines() {
-                    if let Some(target) = line.trim().strip_suffix(':') {
-                        if !target.starts_with('.') && !target.contains(' ') {
-                            scripts.push(Script::new(
-                                &format!("make:{}", target),
-                                &format!("make {}", target),
-                                Some(format!("Run make target: {}", target)),
-                                Some(ScriptType::Serve),
-                                None,
-                            ));
-                        }
-                    }
+        // Add scripts for the packages of the module. When the module root
+        // isn't itself a main package, `run` runs its only command, if any.
+        let packages = find_go_packages(path);
+        let root_is_main = packages.iter().any(|p| p.path == "." && p.is_main);
+        let mains: Vec<&GoPackage> = packages.iter().filter(|p| p.is_main).collect();
+        if !root_is_main && !mains.is_empty() {
+            if let [main] = mains.as_slice() {
+                if let Some(run) = scripts.iter_mut().find(|s| s.name == "run") {
+                    run.command = format!("go run {}", main.import_path());
                 }
+            } else {
+                scripts.retain(|s| s.name != "run");
             }
         }
+        scripts.extend(package_scripts(&packages, &module_name(path)));
+
+        // Add mage targets if present
+        for target in parse_mage_targets(&find_magefiles(path)) {
+            scripts.push(Script::new(
+                &format!("mage:{}", target.name),
+                &format!("mage {}", target.name),
+                Some(
+                    target
+                        .description
+                        .unwrap_or_else(|| format!("Run mage target: {}", target.name)),
+                ),
+                Some(ScriptType::from_script(&target.name, "")),
+                None,
+            ));
+        }
 
         Ok(scripts)
     }
+
+    // Scripts include mage targets and multi-word go commands, so each
+    // script's own command is what runs it.
+    fn script_command(&self, script: &Script) -> Command {
+        shell_command(&script.command)
+    }
+}
+
+/// A directory of the module containing Go source files.
+struct GoPackage {
+    /// The directory's path relative to the module root, `.` for the root.
+    path: String,
+    is_main: bool,
+    has_tests: bool,
+    has_generate: bool,
+}
+
+impl GoPackage {
+    /// Returns the path as a `go` command argument, such as `./cmd/server`.
+    fn import_path(&self) -> String {
+        if self.path == "." {
+            ".".to_string()
+        } else {
+            format!("./{}", self.path)
+        }
+    }
+}
+
+/// Walks the module rooted at `root`, skipping the directories the go tool
+/// ignores (`vendor`, `testdata`, and names starting with `.` or `_`),
+/// `node_modules`, whose size would slow the scan of a project with a
+/// JavaScript front end, and nested modules.
+fn find_go_packages(root: &Path) -> Vec<GoPackage> {
+    let mut packages: Vec<GoPackage> = Vec::new();
+    let walker = WalkDir::new(root).sort_by_file_name().into_iter();
+    let entries = walker.filter_entry(|entry| {
+        if entry.depth() == 0 || !entry.file_type().is_dir() {
+            return true;
+        }
+        let name = entry.file_name().to_string_lossy();
+        !(name.starts_with('.')
+            || name.starts_with('_')
+            || name == "vendor"
+            || name == "node_modules"
+            || name == "testdata"
+            || name == "magefiles"
+            || entry.path().join("go.mod").exists())
+    });
+
+    for entry in entries.filter_map(|e| e.ok()) {
+        let file_name = entry.file_name().to_string_lossy();
+        if !entry.file_type().is_file() || !file_name.ends_with(".go") {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        if is_excluded_by_build_tag(&content) {
+            continue;
+        }
+        let dir = entry.path().parent().unwrap_or(root);
+        let path = match dir.strip_prefix(root) {
+            Ok(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+            Ok(relative) => relative.to_string_lossy().replace('\\', "/"),
+            Err(_) => continue,
+        };
+        let index = match packages.iter().position(|p| p.path == path) {
+            Some(index) => index,
+            None => {
+                packages.push(GoPackage {
+                    path,
+                    is_main: false,
+                    has_tests: false,
+                    has_generate: false,
+                });
+                packages.len() - 1
+            }
+        };
+        let package = &mut packages[index];
+        if file_name.ends_with("_test.go") {
+            package.has_tests = true;
+        } else if package_clause(&content) == Some("main") {
+            package.is_main = true;
+        }
+        if content.lines().any(|line| line.starts_with("//go:generate ")) {
+            package.has_generate = true;
+        }
+    }
+
+    packages.sort_by(|a, b| a.path.cmp(&b.path));
+    packages
+}
+
+/// Returns the package name declared by a Go source file.
+fn package_clause(content: &str) -> Option<&str> {
+    content
+        .lines()
+        .map(|line| line.trim())
+        .find_map(|line| line.strip_prefix("package "))
+        .and_then(|rest| rest.split_whitespace().next())
+}
+
+/// Files constrained to the `ignore` or `mage` build tags, such as generator
+/// programs and magefiles, aren't part of the package.
+fn is_excluded_by_build_tag(content: &str) -> bool {
+    content
+        .lines()
+        .take_while(|line| !line.starts_with("package "))
+        .filter_map(|line| line.strip_prefix("//go:build "))
+        .any(|constraint| {
+            constraint
+                .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '!'))
+                .any(|tag| tag == "ignore" || tag == "mage")
+        })
+}
+
+/// Returns `run:<name>`, `generate` and `test:<pkg>` scripts for the main
+/// packages, `//go:generate` directives and tested packages of the module.
+fn package_scripts(packages: &[GoPackage], module_name: &str) -> Vec<Script> {
+    let mut scripts = Vec::new();
+
+    let mains: Vec<&GoPackage> = packages.iter().filter(|p| p.is_main && p.path != ".").collect();
+    for main in &mains {
+        let base_name = main.path.rsplit('/').next().unwrap_or(&main.path);
+        let is_unique = mains
+            .iter()
+            .filter(|p| p.path.rsplit('/').next() == Some(base_name))
+            .count()
+            == 1;
+        let name = if is_unique { base_name } else { main.path.as_str() };
+        scripts.push(Script::new(
+            &format!("run:{}", name),
+            &format!("go run {}", main.import_path()),
+            Some(format!("Run the {} command", main.path)),
+            Some(ScriptType::Serve),
+            None,
+        ));
+    }
+
+    if packages.iter().any(|p| p.has_generate) {
+        scripts.push(Script::new(
+            "generate",
+            "go generate ./...",
+            Some("Run the //go:generate directives".to_string()),
+            Some(ScriptType::Generate),
+            None,
+        ));
+    }
+
+    for package in packages.iter().filter(|p| p.has_tests) {
+        let name = if package.path == "." { module_name } else { &package.path };
+        scripts.push(Script::new(
+            &format!("test:{}", name),
+            &format!("go test {}", package.import_path()),
+            Some(format!("Run the tests of the {} package", name)),
+            Some(ScriptType::Test),
+            None,
+        ));
+    }
+
+    scripts
+}
+
+/// Returns the last element of the module path declared in go.mod.
+fn module_name(root: &Path) -> String {
+    fs::read_to_string(root.join("go.mod"))
+        .ok()
+        .and_then(|content| {
+            content
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("module "))
+                .map(|module| module.trim().trim_matches('"').to_string())
+        })
+        .and_then(|module| module.rsplit('/').next().map(|name| name.to_string()))
+        .unwrap_or_else(|| "main".to_string())
 }
 
 #[cfg(test)]
@@ -116,5 +310,71 @@ mod tests {
         assert!(scripts.iter().any(|s| s.name == "test" && s.script_type == ScriptType::Test));
         assert!(scripts.iter().any(|s| s.name == "lint" && s.script_type == ScriptType::Lint));
         assert!(scripts.iter().any(|s| s.name == "fmt" && s.script_type == ScriptType::Format));
+        // Makefile targets are listed by the make provider
+        assert!(!scripts.iter().any(|s| s.name.starts_with("make:")));
+    }
+
+    #[test]
+    fn test_find_package_scripts() {
+        let go = GoPackageManager;
+        let temp_dir =
+            create_go_module_project(&std::env::temp_dir().join("go-module-project")).unwrap();
+        let scripts = go.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts
+            .iter()
+            .map(|s| s.name.as_str())
+            .filter(|name| name.contains(':') || *name == "generate")
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "run:migrate",
+                "run:server",
+                "generate",
+                "test:service",
+                "test:internal/auth"
+            ]
+        );
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "run:server" && s.command == "go run ./cmd/server"));
+        assert!(scripts.iter().any(|s| s.name == "test:internal/auth"
+            && s.command == "go test ./internal/auth"
+            && s.script_type == ScriptType::Test));
+        // With several commands and no main package at the root, there's no
+        // single thing for `run` to run
+        assert!(!scripts.iter().any(|s| s.name == "run"));
+    }
+
+    #[test]
+    fn test_find_mage_targets() {
+        let go = GoPackageManager;
+        let temp_dir = create_mage_project(&std::env::temp_dir().join("mage-project")).unwrap();
+        let scripts = go.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts
+            .iter()
+            .map(|s| s.name.as_str())
+            .filter(|name| name.starts_with("mage:"))
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "mage:build",
+                "mage:test",
+                "mage:docker:push",
+                "mage:docker:buildImage"
+            ]
+        );
+        assert!(scripts.iter().any(|s| s.name == "mage:build"
+            && s.command == "mage build"
+            && s.script_type == ScriptType::Build
+            && s.description.as_deref() == Some("Build compiles the binary.")));
+        assert!(scripts.iter().any(|s| s.name == "mage:docker:push"
+            && s.command == "mage docker:push"
+            && s.description.as_deref() == Some("Push pushes the image to the registry.")));
+        // The magefile isn't part of the module's packages
+        assert!(!scripts.iter().any(|s| s.name.starts_with("run:")));
     }
 }
@@ -0,0 +1,567 @@
+use anyhow::Result;
+
+use std::{fs, path::Path, process::Command};
+
+use super::{shell_command, PackageManager};
+use crate::types::{Script, ScriptType};
+
+const GRADLE_BUILD_FILES: &[&str] = &["build.gradle.kts", "build.gradle"];
+const GRADLE_SETTINGS_FILES: &[&str] = &["settings.gradle.kts", "settings.gradle"];
+
+/// The Maven lifecycle phases that are run directly, with their types and
+/// descriptions.
+const MAVEN_PHASES: &[(&str, ScriptType, &str)] = &[
+    ("clean", ScriptType::Clean, "Remove the build output"),
+    ("validate", ScriptType::Lint, "Validate the project"),
+    ("compile", ScriptType::Build, "Compile the sources"),
+    ("test", ScriptType::Test, "Run the unit tests"),
+    ("package", ScriptType::Build, "Package the compiled code"),
+    ("verify", ScriptType::Test, "Run integration checks"),
+    ("install", ScriptType::Deploy, "Install to the local repo"),
+    ("deploy", ScriptType::Publish, "Deploy to the remote repo"),
+];
+
+/// Builds JVM projects (Java, Kotlin, Scala) with Maven or Gradle.
+///
+/// Scripts are found by reading the build files rather than by asking the
+/// build tool, since starting a JVM (let alone the Gradle daemon) takes
+/// longer than psr should.
+pub enum JavaPackageManager {
+    /// `wrapper` is true if the project has a `./mvnw` Maven Wrapper.
+    Maven { wrapper: bool },
+    /// `wrapper` is true if the project has a `./gradlew` Gradle Wrapper.
+    Gradle { wrapper: bool },
+}
+
+impl PackageManager for JavaPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("pom.xml").exists() {
+            Some(Self::Maven {
+                wrapper: dir.join("mvnw").exists(),
+            })
+        } else if GRADLE_BUILD_FILES
+            .iter()
+            .chain(GRADLE_SETTINGS_FILES)
+            .any(|file| dir.join(file).exists())
+        {
+            Some(Self::Gradle {
+                wrapper: dir.join("gradlew").exists(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn tool_name(&self) -> &'static str {
+        match self {
+            Self::Maven { .. } => "mvn",
+            Self::Gradle { .. } => "gradle",
+        }
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new(self.executable());
+        cmd.arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        match self {
+            Self::Maven { .. } => self.find_maven_scripts(path),
+            Self::Gradle { .. } => self.find_gradle_scripts(path),
+        }
+    }
+
+    // Profiles, plugin executions and subproject tasks take arguments, so
+    // each script's own command is what runs it.
+    fn script_command(&self, script: &Script) -> Command {
+        shell_command(&script.command)
+    }
+
+    fn tool_description(&self, _path: &Path) -> Option<String> {
+        Some(self.executable().to_string())
+    }
+}
+
+impl JavaPackageManager {
+    fn executable(&self) -> &'static str {
+        match self {
+            Self::Maven { wrapper: true } => "./mvnw",
+            Self::Maven { wrapper: false } => "mvn",
+            Self::Gradle { wrapper: true } => "./gradlew",
+            Self::Gradle { wrapper: false } => "gradle",
+        }
+    }
+
+    fn find_maven_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let content = strip_xml_comments(&fs::read_to_string(path.join("pom.xml"))?);
+        let mvn = self.executable();
+        let project = children(&content, "project")
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pom.xml has no <project> element"))?;
+
+        let mut scripts: Vec<Script> = MAVEN_PHASES
+            .iter()
+            .map(|(phase, script_type, description)| {
+                let shortcut = match *phase {
+                    "clean" => Some('c'),
+                    "compile" => Some('b'),
+                    "test" => Some('t'),
+                    _ => None,
+                };
+                Script::new(
+                    phase,
+                    &format!("{} {}", mvn, phase),
+                    Some(description.to_string()),
+                    Some(*script_type),
+                    shortcut,
+                )
+            })
+            .collect();
+
+        // Each profile packages the project with the profile activated
+        let profiles = children(project, "profiles")
+            .into_iter()
+            .flat_map(|profiles| children(profiles, "profile"));
+        for profile in profiles {
+            let Some(id) = child_text(profile, "id") else {
+                continue;
+            };
+            scripts.push(Script::new(
+                &format!("profile:{}", id),
+                &format!("{} -P {} package", mvn, id),
+                Some(format!("Package with the {} profile", id)),
+                Some(ScriptType::from_script(&id, "package")),
+                None,
+            ));
+        }
+
+        // Executions of exec-maven-plugin run programs or Java main classes
+        let plugins = children(project, "build")
+            .into_iter()
+            .flat_map(|build| children(build, "plugins"))
+            .flat_map(|plugins| children(plugins, "plugin"))
+            .filter(|plugin| {
+                child_text(plugin, "artifactId").as_deref() == Some("exec-maven-plugin")
+            });
+        for plugin in plugins {
+            let executions = children(plugin, "executions")
+                .into_iter()
+                .flat_map(|executions| children(executions, "execution"));
+            for execution in executions {
+                let Some(id) = child_text(execution, "id") else {
+                    continue;
+                };
+                let goal = children(execution, "goals")
+                    .into_iter()
+                    .flat_map(|goals| children(goals, "goal"))
+                    .map(|goal| goal.trim().to_string())
+                    .next()
+                    .unwrap_or_else(|| "exec".to_string());
+                let configuration = children(execution, "configuration").into_iter().next();
+                let program = configuration.and_then(|c| {
+                    child_text(c, "mainClass").or_else(|| child_text(c, "executable"))
+                });
+                scripts.push(Script::new(
+                    &format!("exec:{}", id),
+                    &format!("{} exec:{}@{}", mvn, goal, id),
+                    program.map(|program| format!("Runs {}", program)),
+                    Some(ScriptType::from_script(&id, &goal)),
+                    None,
+                ));
+            }
+        }
+
+        Ok(scripts)
+    }
+
+    fn find_gradle_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let gradle = self.executable();
+        let build_file = read_first(path, GRADLE_BUILD_FILES);
+
+        let mut scripts = vec![
+            Script::new(
+                "build",
+                &format!("{} build", gradle),
+                Some("Assemble and test the project".to_string()),
+                Some(ScriptType::Build),
+                Some('b'),
+            ),
+            Script::new(
+                "test",
+                &format!("{} test", gradle),
+                Some("Run the unit tests".to_string()),
+                Some(ScriptType::Test),
+                Some('t'),
+            ),
+            Script::new(
+                "check",
+                &format!("{} check", gradle),
+                Some("Run all checks".to_string()),
+                Some(ScriptType::Test),
+                None,
+            ),
+            Script::new(
+                "clean",
+                &format!("{} clean", gradle),
+                Some("Delete the build directory".to_string()),
+                Some(ScriptType::Clean),
+                Some('c'),
+            ),
+        ];
+
+        let mut build_files = vec![(None, build_file)];
+        for subproject in read_first(path, GRADLE_SETTINGS_FILES)
+            .as_deref()
+            .map(gradle_subprojects)
+            .unwrap_or_default()
+        {
+            let dir = path.join(subproject.replace(':', "/"));
+            build_files.push((Some(subproject), read_first(&dir, GRADLE_BUILD_FILES)));
+        }
+
+        for (subproject, content) in build_files {
+            let Some(content) = content else {
+                continue;
+            };
+            // Tasks of subprojects are named `<subproject>:<task>` and run by
+            // their path, as in `./gradlew :app:run`
+            let (prefix, task_path) = match &subproject {
+                Some(subproject) => (format!("{}:", subproject), format!(":{}:", subproject)),
+                None => (String::new(), String::new()),
+            };
+            if let Some(subproject) = &subproject {
+                for (task, script_type) in
+                    [("build", ScriptType::Build), ("test", ScriptType::Test)]
+                {
+                    scripts.push(Script::new(
+                        &format!("{}{}", prefix, task),
+                        &format!("{} {}{}", gradle, task_path, task),
+                        Some(format!("Run the {} task of {}", task, subproject)),
+                        Some(script_type),
+                        None,
+                    ));
+                }
+            }
+            for (task, script_type, description) in plugin_tasks(&content) {
+                scripts.push(Script::new(
+                    &format!("{}{}", prefix, task),
+                    &format!("{} {}{}", gradle, task_path, task),
+                    Some(description.to_string()),
+                    Some(script_type),
+                    None,
+                ));
+            }
+            for task in parse_gradle_tasks(&content) {
+                scripts.push(Script::new(
+                    &format!("{}{}", prefix, task.name),
+                    &format!("{} {}{}", gradle, task_path, task.name),
+                    task.description,
+                    Some(ScriptType::from_script(&task.name, "")),
+                    None,
+                ));
+            }
+        }
+
+        Ok(scripts)
+    }
+}
+
+/// A task registered in a Gradle build script.
+struct GradleTask {
+    name: String,
+    description: Option<String>,
+}
+
+fn read_first(dir: &Path, files: &[&str]) -> Option<String> {
+    files
+        .iter()
+        .find_map(|file| fs::read_to_string(dir.join(file)).ok())
+}
+
+/// Returns the paths of the subprojects that a settings script `include`s,
+/// such as `app` or `libs:core`. The arguments of an `include` can span
+/// lines, up to its closing paren or after a trailing comma.
+fn gradle_subprojects(settings: &str) -> Vec<String> {
+    let mut includes = Vec::new();
+    let mut lines = settings.lines().map(|line| line.trim());
+    while let Some(line) = lines.next() {
+        if !(line.starts_with("include(") || line.starts_with("include ")) {
+            continue;
+        }
+        let mut include = line.to_string();
+        while (include.contains('(') && !include.contains(')')) || include.ends_with(',') {
+            let Some(next) = lines.next() else {
+                break;
+            };
+            include.push(' ');
+            include.push_str(next);
+        }
+        includes.push(include);
+    }
+
+    includes
+        .iter()
+        .flat_map(|include| quoted_strings(include))
+        .map(|path| path.trim_start_matches(':').to_string())
+        .filter(|path| !path.is_empty())
+        .collect()
+}
+
+/// Returns the tasks added by well-known plugins that a build script
+/// applies, such as `run` for the `application` plugin.
+fn plugin_tasks(content: &str) -> Vec<(&'static str, ScriptType, &'static str)> {
+    let mut tasks = Vec::new();
+    let applies = |plugin: &str| {
+        content.lines().map(|line| line.trim()).any(|line| {
+            (line.starts_with("id") || line.starts_with("apply plugin"))
+                && quoted_strings(line).iter().any(|s| s == plugin)
+        }) || content.lines().any(|line| line.trim() == plugin)
+    };
+    if applies("application") {
+        tasks.push(("run", ScriptType::Serve, "Run the application"));
+    }
+    if applies("org.springframework.boot") {
+        tasks.push((
+            "bootRun",
+            ScriptType::Serve,
+            "Run the Spring Boot application",
+        ));
+    }
+    tasks
+}
+
+/// Finds tasks defined with `tasks.register("name")` (Kotlin or Groovy DSL),
+/// `tasks.create("name")` or Groovy's `task name`, along with the
+/// `description` set in the task's configuration block.
+fn parse_gradle_tasks(content: &str) -> Vec<GradleTask> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut tasks = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let line = line.trim();
+        let name = if let Some(rest) = line
+            .strip_prefix("tasks.register")
+            .or_else(|| line.strip_prefix("tasks.create"))
+        {
+            // Skip a Kotlin type argument, as in `register<Copy>("name")`
+            let rest = rest.split_once('(').map(|(_, rest)| rest).unwrap_or("");
+            quoted_strings(rest).into_iter().next()
+        } else if let Some(rest) = line
+            .strip_prefix("task ")
+            .or_else(|| line.strip_prefix("task("))
+        {
+            let rest = rest.trim_start();
+            if rest.starts_with(['"', '\'']) {
+                quoted_strings(rest).into_iter().next()
+            } else {
+                let name: String = rest
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect();
+                (!name.is_empty()).then_some(name)
+            }
+        } else {
+            None
+        };
+        let Some(name) = name else {
+            continue;
+        };
+
+        // Look for a description in the task's configuration block
+        let mut depth = 0;
+        let mut description = None;
+        for block_line in &lines[i..] {
+            let block_line = block_line.trim();
+            if depth > 0 || block_line.contains('{') {
+                if let Some(rest) = block_line.strip_prefix("description") {
+                    description = quoted_strings(rest).into_iter().next();
+                }
+            }
+            depth += block_line.matches('{').count() as i32;
+            depth -= block_line.matches('}').count() as i32;
+            if depth <= 0 {
+                break;
+            }
+        }
+        tasks.push(GradleTask { name, description });
+    }
+
+    tasks
+}
+
+/// Returns the single- or double-quoted strings in a line.
+fn quoted_strings(line: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find(['"', '\'']) {
+        let quote = rest[start..].chars().next().unwrap_or('"');
+        let after = &rest[start + 1..];
+        let Some(end) = after.find(quote) else {
+            break;
+        };
+        strings.push(after[..end].to_string());
+        rest = &after[end + 1..];
+    }
+    strings
+}
+
+fn strip_xml_comments(content: &str) -> String {
+    let mut result = String::new();
+    let mut rest = content;
+    while let Some(start) = rest.find("<!--") {
+        result.push_str(&rest[..start]);
+        rest = match rest[start..].find("-->") {
+            Some(end) => &rest[start + end + 3..],
+            None => "",
+        };
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Returns the contents of the elements named `tag` that are direct children
+/// of the XML fragment `xml`.
+fn children<'a>(xml: &'a str, tag: &str) -> Vec<&'a str> {
+    let mut elements = Vec::new();
+    let mut depth = 0;
+    let mut start = None;
+    let mut i = 0;
+    while let Some(offset) = xml[i..].find('<') {
+        let open = i + offset;
+        let Some(close) = xml[open..].find('>').map(|c| open + c) else {
+            break;
+        };
+        let token = &xml[open + 1..close];
+        i = close + 1;
+        if token.starts_with('?') || token.starts_with('!') {
+            continue;
+        }
+        if let Some(name) = token.strip_prefix('/') {
+            depth -= 1;
+            if depth == 0 && name.trim() == tag {
+                if let Some(start) = start.take() {
+                    elements.push(&xml[start..open]);
+                }
+            }
+        } else {
+            let name = token.split_whitespace().next().unwrap_or_default();
+            if token.ends_with('/') {
+                if depth == 0 && name.trim_end_matches('/') == tag {
+                    elements.push("");
+                }
+            } else {
+                if depth == 0 && name == tag {
+                    start = Some(close + 1);
+                }
+                depth += 1;
+            }
+        }
+    }
+    elements
+}
+
+/// Returns the text of the first direct child named `tag`.
+fn child_text(xml: &str, tag: &str) -> Option<String> {
+    children(xml, tag)
+        .into_iter()
+        .next()
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_maven_scripts() {
+        let temp_dir = create_maven_project(&std::env::temp_dir().join("maven-project")).unwrap();
+        let maven = JavaPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = maven.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            [
+                "clean",
+                "validate",
+                "compile",
+                "test",
+                "package",
+                "verify",
+                "install",
+                "deploy",
+                "profile:native",
+                "profile:release",
+                "exec:seed",
+                "exec:codegen"
+            ]
+        );
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "./mvnw test"
+            && s.script_type == ScriptType::Test));
+        // Like `cargo install`, installing locally is a deployment
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "install" && s.script_type == ScriptType::Deploy));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "profile:native" && s.command == "./mvnw -P native package"));
+        assert!(scripts.iter().any(|s| s.name == "exec:seed"
+            && s.command == "./mvnw exec:java@seed"
+            && s.description.as_deref() == Some("Runs com.example.Seed")));
+        assert!(scripts.iter().any(|s| s.name == "exec:codegen"
+            && s.command == "./mvnw exec:exec@codegen"
+            && s.description.as_deref() == Some("Runs protoc")));
+    }
+
+    #[test]
+    fn test_find_gradle_scripts() {
+        let temp_dir = create_gradle_project(&std::env::temp_dir().join("gradle-project")).unwrap();
+        let gradle = JavaPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = gradle.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            [
+                "build",
+                "test",
+                "check",
+                "clean",
+                "generateDocs",
+                "app:build",
+                "app:test",
+                "app:run",
+                "app:integrationTest",
+                "libs:core:build",
+                "libs:core:test",
+                "libs:core:printVersion"
+            ]
+        );
+        assert!(scripts.iter().any(|s| s.name == "generateDocs"
+            && s.command == "gradle generateDocs"
+            && s.description.as_deref() == Some("Generates the API documentation")));
+        assert!(scripts.iter().any(|s| s.name == "app:run"
+            && s.command == "gradle :app:run"
+            && s.script_type == ScriptType::Serve));
+        assert!(scripts.iter().any(|s| s.name == "app:integrationTest"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Runs the integration tests")));
+        assert!(scripts.iter().any(|s| s.name == "libs:core:printVersion"
+            && s.command == "gradle :libs:core:printVersion"
+            && s.description.is_none()));
+    }
+
+    #[test]
+    fn test_gradle_subprojects_spanning_lines() {
+        let kotlin = "rootProject.name = \"demo\"\ninclude(\n    \"app\",\n    \":libs:core\"\n)\n";
+        assert_eq!(gradle_subprojects(kotlin), ["app", "libs:core"]);
+
+        let groovy = "include 'app',\n        'libs:core'\ninclude 'docs'\n";
+        assert_eq!(gradle_subprojects(groovy), ["app", "libs:core", "docs"]);
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::Result;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use super::PackageManager;
+use crate::types::{Script, ScriptType};
+
+const JUSTFILE_NAMES: &[&str] = &["justfile", "Justfile", ".justfile"];
+
+pub struct JustPackageManager;
+
+#[derive(Debug)]
+struct Recipe {
+    name: String,
+    doc: Option<String>,
+    parameters: Vec<String>,
+    body: Vec<String>,
+}
+
+impl PackageManager for JustPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        find_justfile(dir).map(|_| JustPackageManager)
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "just"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("just");
+        cmd.arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let justfile = find_justfile(path).ok_or_else(|| anyhow::anyhow!("justfile not found"))?;
+        let content = fs::read_to_string(justfile)?;
+
+        let scripts = parse_recipes(&content)
+            .into_iter()
+            .map(|recipe| {
+                let description = match (recipe.doc, recipe.parameters.is_empty()) {
+                    (Some(doc), true) => Some(doc),
+                    (Some(doc), false) => Some(format!(
+                        "{} (parameters: {})",
+                        doc,
+                        recipe.parameters.join(", ")
+                    )),
+                    (None, false) => Some(format!("Parameters: {}", recipe.parameters.join(", "))),
+                    (None, true) => None,
+                };
+                let script_type = ScriptType::from_script(&recipe.name, &recipe.body.join("\n"));
+                Script::new(
+                    &recipe.name,
+                    &format!("just {}", recipe.name),
+                    description,
+                    Some(script_type),
+                    None,
+                )
+            })
+            .collect();
+
+        Ok(scripts)
+    }
+}
+
+fn find_justfile(dir: &Path) -> Option<PathBuf> {
+    JUSTFILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// Extracts the public recipes from the contents of a justfile, in file order.
+///
+/// Recipes whose names start with `_` or that carry a `[private]` attribute
+/// are omitted, matching `just --list`.
+fn parse_recipes(content: &str) -> Vec<Recipe> {
+    let mut recipes: Vec<Recipe> = Vec::new();
+    let mut doc: Option<String> = None;
+    let mut private = false;
+    let mut in_recipe = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        // Indented lines belong to the body of the preceding recipe
+        if line.starts_with(' ') || line.starts_with('\t') {
+            if in_recipe && !trimmed.is_empty() {
+                if let Some(recipe) = recipes.last_mut() {
+                    recipe.body.push(trimmed.to_string());
+                }
+            }
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            doc = None;
+            private = false;
+            in_recipe = false;
+            continue;
+        }
+        in_recipe = false;
+
+        if let Some(comment) = trimmed.strip_prefix('#') {
+            // Shebang lines and `#!` directives are not documentation
+            if !comment.starts_with('!') {
+                doc = Some(comment.trim().to_string());
+            }
+            continue;
+        }
+
+        if let Some(attributes) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            if attributes.split(',').any(|a| a.trim() == "private") {
+                private = true;
+            }
+            continue;
+        }
+
+        if let Some((name, parameters)) = parse_recipe_header(trimmed) {
+            if !private && !name.starts_with('_') {
+                recipes.push(Recipe {
+                    name,
+                    doc: doc.take(),
+                    parameters,
+                    body: Vec::new(),
+                });
+                in_recipe = true;
+            }
+        }
+        doc = None;
+        private = false;
+    }
+
+    recipes
+}
+
+/// Parses a recipe header such as `@test target *flags: build`, returning the
+/// recipe name and its parameters. Returns `None` for settings, aliases,
+/// variable assignments and other non-recipe lines.
+fn parse_recipe_header(line: &str) -> Option<(String, Vec<String>)> {
+    let first_word = line.split_whitespace().next()?;
+    if ["set", "alias", "export", "import", "mod"].contains(&first_word) {
+        return None;
+    }
+
+    // Split the header into words, keeping quoted parameter defaults intact.
+    // The header ends at the first unquoted `:`; a `:=` means an assignment.
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quote: Option<char> = None;
+    let mut chars = line.chars().peekable();
+    loop {
+        let c = chars.next()?;
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, ':') if chars.peek() == Some(&'=') => return None,
+            (None, ':') => break,
+            (None, c) if c.is_whitespace() => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    let mut words = words.into_iter();
+    let name = words.next()?;
+    let name = name.trim_start_matches('@');
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !is_identifier {
+        return None;
+    }
+
+    let parameters = words.collect();
+    Some((name.to_string(), parameters))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir = create_just_project(&std::env::temp_dir().join("just-project")).unwrap();
+        let just = JustPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = just.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "build"
+            && s.command == "just build"
+            && s.script_type == ScriptType::Build
+            && s.description.as_deref() == Some("Build the release binary")));
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref()
+                == Some("Run the tests (parameters: filter=\"\", +flags)")));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "fmt" && s.script_type == ScriptType::Format));
+        assert!(!scripts
+            .iter()
+            .any(|s| s.name == "_helper" || s.name == "secret"));
+        assert!(!scripts.iter().any(|s| s.name == "version" || s.name == "b"));
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A target defined by an exported function of a magefile.
+#[derive(Debug)]
+pub(super) struct MageTarget {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Returns the files that define the project's mage targets: the `.go` files
+/// of a `magefiles` directory, or top-level files with the `mage` build tag.
+pub(super) fn find_magefiles(dir: &Path) -> Vec<PathBuf> {
+    let magefiles_dir = dir.join("magefiles");
+    let (search_dir, require_tag) = if magefiles_dir.is_dir() {
+        (magefiles_dir, false)
+    } else {
+        (dir.to_path_buf(), true)
+    };
+    let mut files: Vec<PathBuf> = fs::read_dir(search_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            name.ends_with(".go") && !name.ends_with("_test.go")
+        })
+        .filter(|path| {
+            !require_tag
+                || fs::read_to_string(path).is_ok_and(|content| has_mage_build_tag(&content))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+fn has_mage_build_tag(content: &str) -> bool {
+    content
+        .lines()
+        .take_while(|line| !line.starts_with("package "))
+        .any(|line| {
+            let constraint = line
+                .strip_prefix("//go:build ")
+                .or_else(|| line.strip_prefix("// +build "));
+            constraint.is_some_and(|c| c.split_whitespace().any(|tag| tag == "mage"))
+        })
+}
+
+/// Parses the targets of a set of magefiles, as `mage -l` lists them.
+///
+/// Exported functions are targets, and methods of types declared as
+/// `mg.Namespace` are namespaced targets such as `docker:build`. The first
+/// sentence of a function's doc comment becomes its description.
+pub(super) fn parse_mage_targets(files: &[PathBuf]) -> Vec<MageTarget> {
+    let contents: Vec<String> = files
+        .iter()
+        .filter_map(|file| fs::read_to_string(file).ok())
+        .collect();
+
+    let namespaces: Vec<&str> = contents
+        .iter()
+        .flat_map(|content| content.lines())
+        .filter_map(|line| {
+            let (name, kind) = line
+                .strip_prefix("type ")?
+                .split_once(char::is_whitespace)?;
+            (kind.trim() == "mg.Namespace").then_some(name)
+        })
+        .collect();
+
+    let mut targets = Vec::new();
+    for content in &contents {
+        let mut doc: Vec<&str> = Vec::new();
+        for line in content.lines() {
+            if let Some(comment) = line.strip_prefix("//") {
+                if !comment.starts_with("go:") && !comment.starts_with(" +build") {
+                    doc.push(comment.trim());
+                }
+                continue;
+            }
+            let comments = std::mem::take(&mut doc);
+            let Some(signature) = line.strip_prefix("func ") else {
+                continue;
+            };
+
+            let (namespace, signature) = match signature.strip_prefix('(') {
+                Some(rest) => {
+                    let Some((receiver, rest)) = rest.split_once(')') else {
+                        continue;
+                    };
+                    let receiver_type = receiver.split_whitespace().last().unwrap_or_default();
+                    if !namespaces.contains(&receiver_type) {
+                        continue;
+                    }
+                    (Some(receiver_type), rest.trim_start())
+                }
+                None => (None, signature),
+            };
+            let function_name = signature.split('(').next().unwrap_or_default().trim();
+            if !function_name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                continue;
+            }
+
+            let name = match namespace {
+                Some(namespace) => {
+                    format!("{}:{}", lower_first(namespace), lower_first(function_name))
+                }
+                None => lower_first(function_name),
+            };
+            targets.push(MageTarget {
+                name,
+                description: synopsis(&comments),
+            });
+        }
+    }
+
+    targets
+}
+
+fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Returns the first sentence of a doc comment.
+fn synopsis(comments: &[&str]) -> Option<String> {
+    let text = comments.join(" ");
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    match text.find(". ") {
+        Some(end) => Some(text[..=end].to_string()),
+        None => Some(text.to_string()),
+    }
+}
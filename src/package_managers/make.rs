@@ -0,0 +1,330 @@
+use anyhow::{Context, Result};
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use super::{diagnostics, PackageManager, Warning};
+use crate::types::{Script, ScriptType};
+
+const MAKEFILE_NAMES: &[&str] = &["GNUmakefile", "makefile", "Makefile"];
+
+pub struct MakePackageManager;
+
+#[derive(Debug)]
+struct MakeTarget {
+    name: String,
+    description: Option<String>,
+    recipe: Vec<String>,
+}
+
+impl MakeTarget {
+    fn script_type(&self) -> ScriptType {
+        ScriptType::from_script(&self.name, &self.recipe.join("\n"))
+    }
+}
+
+impl PackageManager for MakePackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        find_makefile(dir).map(|_| MakePackageManager)
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "make"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("make");
+        cmd.arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let makefile = find_makefile(path).ok_or_else(|| anyhow::anyhow!("Makefile not found"))?;
+
+        let scripts = parse_makefile(&makefile)?
+            .targets
+            .into_iter()
+            .map(|target| {
+                Script::new(
+                    &target.name,
+                    &format!("make {}", target.name),
+                    target.description.clone(),
+                    Some(target.script_type()),
+                    None,
+                )
+            })
+            .collect();
+
+        Ok(scripts)
+    }
+
+    fn warnings(&self, path: &Path) -> Vec<Warning> {
+        let Some(makefile) = find_makefile(path) else {
+            return Vec::new();
+        };
+        let Ok(parsed) = parse_makefile(&makefile) else {
+            return Vec::new();
+        };
+        parsed
+            .missing_includes
+            .iter()
+            .map(|include| {
+                diagnostics::missing_include(
+                    &makefile,
+                    include.strip_prefix(path).unwrap_or(include),
+                )
+            })
+            .collect()
+    }
+}
+
+fn find_makefile(dir: &Path) -> Option<PathBuf> {
+    MAKEFILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// Parses the runnable targets of a Makefile, following `include` directives.
+///
+/// Special targets (`.PHONY`, `.DEFAULT`, ...), pattern rules and targets
+/// that name files (e.g. `bin/app` or `main.o`) are skipped unless they are
+/// declared `.PHONY`. A `## comment` on the line above a target, or after its
+/// prerequisites, becomes its description. A missing `include` file is
+/// skipped and recorded, since a rule may generate it when make runs.
+fn parse_makefile(path: &Path) -> Result<Makefile> {
+    let mut parser = MakefileParser::default();
+    parser.parse_file(path, true)?;
+    let missing_includes = std::mem::take(&mut parser.missing_includes);
+    Ok(Makefile {
+        targets: parser.into_targets(),
+        missing_includes,
+    })
+}
+
+struct Makefile {
+    targets: Vec<MakeTarget>,
+    missing_includes: Vec<PathBuf>,
+}
+
+#[derive(Default)]
+struct MakefileParser {
+    targets: Vec<MakeTarget>,
+    phony: HashSet<String>,
+    visited: HashSet<PathBuf>,
+    missing_includes: Vec<PathBuf>,
+}
+
+impl MakefileParser {
+    fn parse_file(&mut self, path: &Path, required: bool) -> Result<()> {
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if !self.visited.insert(canonical) {
+            return Ok(());
+        }
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(_) if !required => return Ok(()),
+            Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+        };
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+
+        let mut doc: Option<String> = None;
+        let mut in_define = false;
+        let mut current: Option<usize> = None;
+
+        for line in join_continuation_lines(&content) {
+            if in_define {
+                in_define = line.trim() != "endef";
+                continue;
+            }
+
+            // Recipe lines are indented with a tab
+            if let Some(command) = line.strip_prefix('\t') {
+                if let Some(index) = current {
+                    let command = command.trim();
+                    if !command.is_empty() && !command.starts_with('#') {
+                        self.targets[index].recipe.push(command.to_string());
+                    }
+                }
+                continue;
+            }
+
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                doc = None;
+                continue;
+            }
+            if let Some(comment) = trimmed.strip_prefix("##") {
+                doc = Some(comment.trim().to_string());
+                continue;
+            }
+            if trimmed.starts_with('#') || line.starts_with(char::is_whitespace) {
+                continue;
+            }
+            current = None;
+
+            let first_word = trimmed.split_whitespace().next().unwrap_or_default();
+            match first_word {
+                "define" => {
+                    in_define = true;
+                    continue;
+                }
+                "include" | "-include" | "sinclude" => {
+                    for file in trimmed.split_whitespace().skip(1) {
+                        if file.contains(['$', '*', '?']) {
+                            continue;
+                        }
+                        let include = base_dir.join(file);
+                        if first_word == "include" && !include.exists() {
+                            self.missing_includes.push(include);
+                        } else {
+                            self.parse_file(&include, first_word == "include")?;
+                        }
+                    }
+                    continue;
+                }
+                "ifeq" | "ifneq" | "ifdef" | "ifndef" | "else" | "endif" | "export"
+                | "unexport" | "override" | "vpath" => continue,
+                _ => {}
+            }
+
+            if let Some(index) = self.parse_rule(trimmed, doc.take()) {
+                current = Some(index);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the targets of a rule line, returning the index of the first
+    /// one so that following recipe lines can be attached to it.
+    fn parse_rule(&mut self, line: &str, doc: Option<String>) -> Option<usize> {
+        let (line, inline_doc) = match line.split_once("##") {
+            Some((rule, comment)) => (rule, Some(comment.trim().to_string())),
+            None => (line.split('#').next().unwrap_or_default(), None),
+        };
+        let (targets, rest) = line.split_once(':')?;
+
+        // Variable assignments (`A = b`, `A := b`, `A ::= b`, `A ?= b`, ...)
+        let rest = rest.trim_start_matches(':');
+        if targets.contains('=') || rest.starts_with('=') {
+            return None;
+        }
+        // Target-specific variables (`target: VAR = value`)
+        if rest.contains('=') {
+            return None;
+        }
+
+        let targets: Vec<&str> = targets.split_whitespace().collect();
+        if targets == [".PHONY"] {
+            self.phony
+                .extend(rest.split_whitespace().map(|s| s.to_string()));
+            return None;
+        }
+
+        let description = inline_doc.or(doc);
+        let mut first_index = None;
+        for name in targets {
+            if name.starts_with('.') || name.contains(['%', '$']) {
+                continue;
+            }
+            let index = match self.targets.iter().position(|t| t.name == name) {
+                Some(index) => index,
+                None => {
+                    self.targets.push(MakeTarget {
+                        name: name.to_string(),
+                        description: None,
+                        recipe: Vec::new(),
+                    });
+                    self.targets.len() - 1
+                }
+            };
+            if self.targets[index].description.is_none() {
+                self.targets[index].description = description.clone();
+            }
+            first_index.get_or_insert(index);
+        }
+        first_index
+    }
+
+    fn into_targets(self) -> Vec<MakeTarget> {
+        let phony = self.phony;
+        self.targets
+            .into_iter()
+            .filter(|t| phony.contains(&t.name) || !t.name.contains(['/', '.']))
+            .collect()
+    }
+}
+
+fn join_continuation_lines(content: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut pending = String::new();
+    for line in content.lines() {
+        match line.strip_suffix('\\') {
+            Some(head) => {
+                pending.push_str(head);
+                pending.push(' ');
+            }
+            None => {
+                pending.push_str(line);
+                lines.push(std::mem::take(&mut pending));
+            }
+        }
+    }
+    if !pending.is_empty() {
+        lines.push(pending);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir = create_make_project(&std::env::temp_dir().join("make-project")).unwrap();
+        let make = MakePackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = make.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            ["all", "build", "test", "docs.html", "lint", "release"]
+        );
+        assert!(scripts.iter().any(|s| s.name == "build"
+            && s.command == "make build"
+            && s.script_type == ScriptType::Build
+            && s.description.as_deref() == Some("Compile the program")));
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Run the test suite")));
+        assert!(scripts.iter().any(|s| s.name == "lint"
+            && s.script_type == ScriptType::Lint
+            && s.description.as_deref() == Some("Lint the sources")));
+        assert!(make.warnings(&temp_dir.dir).is_empty());
+    }
+
+    #[test]
+    fn test_missing_include() {
+        let temp_dir =
+            create_make_project(&std::env::temp_dir().join("make-missing-include")).unwrap();
+        temp_dir
+            .create_file("Makefile", "include rules.mk config.mk\n\nbuild:\n\tcc main.c\n")
+            .unwrap();
+        let make = MakePackageManager::detect(&temp_dir.dir).unwrap();
+
+        let scripts = make.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["lint", "build"]);
+
+        let warnings = make.warnings(&temp_dir.dir);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("config.mk"));
+    }
+}
@@ -1,36 +1,168 @@
+mod cargo_make;
+mod cmake;
+mod diagnostics;
+mod elixir;
 mod go;
+mod java;
+mod just;
+mod mage;
+mod make;
+mod monorepo;
 mod node;
+mod nox;
+mod php;
 mod python;
+mod ruby;
 mod rust;
+mod task;
+mod tox;
+mod workspace;
+mod zig;
 
 use anyhow::Result;
-use std::{path::Path, process::Command};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 use crate::types::Script;
 
+use cmake::CMakePackageManager;
+use elixir::ElixirPackageManager;
 use go::GoPackageManager;
+use java::JavaPackageManager;
+use just::JustPackageManager;
+use make::MakePackageManager;
 use node::NodePackageManager;
+use nox::NoxPackageManager;
+use php::PhpPackageManager;
 use python::PythonPackageManager;
+use ruby::RubyPackageManager;
 use rust::RustPackageManager;
+use task::TaskPackageManager;
+use tox::ToxPackageManager;
+use zig::ZigPackageManager;
+
+pub use diagnostics::{unparsable_config, Warning};
+pub use workspace::dependency_order;
 
 pub trait PackageManager {
     fn detect(dir: &Path) -> Option<Self>
     where
         Self: Sized;
+    /// Returns the name of the tool that runs the scripts, such as `npm` or
+    /// `cargo`, which prefixes script names that collide with another
+    /// provider's in the same project.
+    fn tool_name(&self) -> &'static str;
     fn find_scripts(&self, path: &Path) -> Result<Vec<Script>>;
     fn run_command(&self, script: &str) -> Command;
+
+    /// Returns the command that runs `script`. By default this runs the script
+    /// by name; providers whose scripts don't all share one runner override it.
+    fn script_command(&self, script: &Script) -> Command {
+        self.run_command(&script.name)
+    }
+
+    /// Returns the member packages of the workspace rooted at `path`, if it
+    /// is one.
+    fn workspace_members(&self, _path: &Path) -> Vec<WorkspaceMember> {
+        Vec::new()
+    }
+
+    /// Returns the name of the tool that runs the scripts in `path`, with
+    /// its version when the project pins one, for display.
+    fn tool_description(&self, _path: &Path) -> Option<String> {
+        None
+    }
+
+    /// Returns problems with the project's configuration, such as the lock
+    /// files of competing tools.
+    fn warnings(&self, _path: &Path) -> Vec<Warning> {
+        Vec::new()
+    }
 }
 
-pub fn detect_package_manager_in_dir(dir: &Path) -> Option<Box<dyn PackageManager>> {
-    if let Some(npm) = NodePackageManager::detect(dir) {
-        Some(Box::new(npm))
-    } else if let Some(rust) = RustPackageManager::detect(dir) {
-        Some(Box::new(rust))
-    } else if let Some(python) = PythonPackageManager::detect(dir) {
-        Some(Box::new(python))
-    } else if let Some(go) = GoPackageManager::detect(dir) {
-        Some(Box::new(go))
+/// A member package of a workspace, such as an npm workspace.
+pub struct WorkspaceMember {
+    pub name: String,
+    pub path: PathBuf,
+    pub package_manager: Box<dyn PackageManager>,
+    /// The names of the packages the member depends on, which may include
+    /// packages outside the workspace.
+    pub dependencies: Vec<String>,
+}
+
+/// Returns a command that runs `command` through the shell, passing any
+/// arguments added to the returned `Command` through to it.
+pub fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(format!("{} \"$@\"", command)).arg("sh");
+    cmd
+}
+
+/// Quotes `word` for the shell if it contains anything other than letters,
+/// digits and path punctuation.
+fn shell_quote(word: &str) -> String {
+    let is_plain = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'));
+    if is_plain {
+        word.to_string()
     } else {
-        None
+        format!("'{}'", word.replace('\'', "'\\''"))
+    }
+}
+
+/// Returns the providers of every ecosystem and task runner found in `dir`,
+/// such as both Node and Rust for a Tauri app, or Rust and just for a crate
+/// that drives its builds through a justfile.
+pub fn detect_package_managers_in_dir(dir: &Path) -> Vec<Box<dyn PackageManager>> {
+    let mut package_managers: Vec<Box<dyn PackageManager>> = Vec::new();
+    if let Some(npm) = NodePackageManager::detect(dir) {
+        package_managers.push(Box::new(npm));
+    }
+    if let Some(rust) = RustPackageManager::detect(dir) {
+        package_managers.push(Box::new(rust));
+    }
+    if let Some(python) = PythonPackageManager::detect(dir) {
+        package_managers.push(Box::new(python));
+    }
+    if let Some(go) = GoPackageManager::detect(dir) {
+        package_managers.push(Box::new(go));
+    }
+    if let Some(ruby) = RubyPackageManager::detect(dir) {
+        package_managers.push(Box::new(ruby));
+    }
+    if let Some(php) = PhpPackageManager::detect(dir) {
+        package_managers.push(Box::new(php));
+    }
+    if let Some(elixir) = ElixirPackageManager::detect(dir) {
+        package_managers.push(Box::new(elixir));
+    }
+    if let Some(java) = JavaPackageManager::detect(dir) {
+        package_managers.push(Box::new(java));
+    }
+    if let Some(zig) = ZigPackageManager::detect(dir) {
+        package_managers.push(Box::new(zig));
+    }
+    if let Some(cmake) = CMakePackageManager::detect(dir) {
+        package_managers.push(Box::new(cmake));
+    }
+    if let Some(tox) = ToxPackageManager::detect(dir) {
+        package_managers.push(Box::new(tox));
+    }
+    if let Some(nox) = NoxPackageManager::detect(dir) {
+        package_managers.push(Box::new(nox));
+    }
+    if let Some(just) = JustPackageManager::detect(dir) {
+        package_managers.push(Box::new(just));
+    }
+    if let Some(task) = TaskPackageManager::detect(dir) {
+        package_managers.push(Box::new(task));
+    }
+    if let Some(make) = MakePackageManager::detect(dir) {
+        package_managers.push(Box::new(make));
     }
+    package_managers
 }
@@ -0,0 +1,132 @@
+use anyhow::Result;
+use serde_json::Value;
+use walkdir::WalkDir;
+
+use std::{fs, path::Path};
+
+use super::node::strip_jsonc;
+
+/// A task of a monorepo build system: a Turborepo pipeline task, or the
+/// target of an Nx project.
+pub(super) struct PipelineTask {
+    pub name: String,
+    pub command: String,
+    pub description: Option<String>,
+    pub depends_on: Vec<String>,
+}
+
+/// Returns the tasks of `turbo.json`: the `tasks` of Turborepo 2, or the
+/// `pipeline` of earlier versions. Package-specific tasks such as
+/// `web#build` are run as written; root tasks such as `//#lint` by name.
+pub(super) fn find_turbo_tasks(dir: &Path) -> Result<Vec<PipelineTask>> {
+    let Ok(content) = fs::read_to_string(dir.join("turbo.json")) else {
+        return Ok(Vec::new());
+    };
+    let turbo: Value = serde_json::from_str(&strip_jsonc(&content))?;
+    let tasks = turbo
+        .get("tasks")
+        .or_else(|| turbo.get("pipeline"))
+        .and_then(|t| t.as_object());
+
+    let mut pipeline_tasks: Vec<PipelineTask> = Vec::new();
+    for (key, task) in tasks.into_iter().flatten() {
+        let task_name = key.strip_prefix("//#").unwrap_or(key);
+        if pipeline_tasks.iter().any(|t| t.name == task_name) {
+            continue;
+        }
+        pipeline_tasks.push(PipelineTask {
+            name: task_name.to_string(),
+            command: format!("turbo run {}", task_name),
+            description: None,
+            depends_on: depends_on(task),
+        });
+    }
+    Ok(pipeline_tasks)
+}
+
+/// Returns the targets of the Nx projects configured by `project.json` files
+/// in the workspace, named `<project>:<target>`. Targets without their own
+/// `dependsOn` use the `targetDefaults` of `nx.json`.
+pub(super) fn find_nx_targets(dir: &Path) -> Result<Vec<PipelineTask>> {
+    let Ok(content) = fs::read_to_string(dir.join("nx.json")) else {
+        return Ok(Vec::new());
+    };
+    let nx: Value = serde_json::from_str(&strip_jsonc(&content))?;
+    let target_defaults = nx.get("targetDefaults");
+
+    let project_files = WalkDir::new(dir)
+        .max_depth(4)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.depth() == 0
+                || !(name.starts_with('.') || name == "node_modules" || name == "dist")
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name() == "project.json");
+
+    let mut targets = Vec::new();
+    for entry in project_files {
+        let Some(project) = fs::read_to_string(entry.path())
+            .ok()
+            .and_then(|content| serde_json::from_str::<Value>(&strip_jsonc(&content)).ok())
+        else {
+            continue;
+        };
+        let project_dir = entry.path().parent().unwrap_or(dir);
+        let Some(project_name) = project
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map(|n| n.to_string())
+            .or_else(|| {
+                let name = project_dir.file_name()?.to_string_lossy();
+                Some(name.into_owned())
+            })
+        else {
+            continue;
+        };
+
+        let project_targets = project.get("targets").and_then(|t| t.as_object());
+        for (target_name, target) in project_targets.into_iter().flatten() {
+            let mut target_depends_on = depends_on(target);
+            if target_depends_on.is_empty() {
+                let defaults = target_defaults.and_then(|d| d.get(target_name));
+                target_depends_on = defaults.map(depends_on).unwrap_or_default();
+            }
+            let name = format!("{}:{}", project_name, target_name);
+            targets.push(PipelineTask {
+                command: format!("nx run {}", name),
+                description: target
+                    .get("metadata")
+                    .and_then(|m| m.get("description"))
+                    .and_then(|d| d.as_str())
+                    .map(|d| d.to_string()),
+                depends_on: target_depends_on,
+                name,
+            });
+        }
+    }
+    Ok(targets)
+}
+
+/// Returns a task's `dependsOn` entries, which are strings such as `^build`
+/// or (in Nx) objects with a `target` and optional `projects`.
+fn depends_on(task: &Value) -> Vec<String> {
+    task.get("dependsOn")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|dependency| match dependency {
+            Value::String(s) => Some(s.clone()),
+            Value::Object(o) => {
+                let target = o.get("target")?.as_str()?;
+                match o.get("dependencies").and_then(|d| d.as_bool()) {
+                    Some(true) => Some(format!("^{}", target)),
+                    _ => Some(target.to_string()),
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
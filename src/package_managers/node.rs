@@ -1,11 +1,20 @@
 use anyhow::Result;
 use serde::Deserialize;
 
-use std::{collections::HashMap, fs, path::Path, process::Command};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
-use super::PackageManager;
-use crate::types::{Phase, Script, ScriptType};
+use super::monorepo::{find_nx_targets, find_turbo_tasks};
+use super::workspace::expand_workspace_pattern;
+use super::diagnostics::{lock_file_conflict, unparsable_config};
+use super::{shell_command, PackageManager, Warning, WorkspaceMember};
+use crate::types::{Phase, Pipeline, Script, ScriptType};
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum NodePackageManager {
     Npm,
     Yarn,
@@ -16,12 +25,116 @@ pub enum NodePackageManager {
 
 #[derive(Deserialize)]
 struct PackageJson {
+    name: Option<String>,
     scripts: Option<HashMap<String, String>>,
     #[serde(default)]
     descriptions: HashMap<String, String>, // Optional script descriptions
+    workspaces: Option<Workspaces>,
+    #[serde(default)]
+    dependencies: HashMap<String, serde_json::Value>,
+    #[serde(default, rename = "devDependencies")]
+    dev_dependencies: HashMap<String, serde_json::Value>,
+}
+
+/// The `workspaces` field of package.json: a list of member paths, or (in
+/// Yarn) an object with a `packages` list.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Workspaces {
+    Paths(Vec<String>),
+    Config {
+        #[serde(default)]
+        packages: Vec<String>,
+    },
+}
+
+#[derive(Deserialize)]
+struct PnpmWorkspace {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct DenoJson {
+    #[serde(default)]
+    tasks: HashMap<String, DenoTask>,
 }
 
+/// A `deno.json` task: either a command string, or (Deno 2.1+) an object with
+/// an optional command, description and task dependencies.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DenoTask {
+    Command(String),
+    Definition {
+        #[serde(default)]
+        command: Option<String>,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(default)]
+        dependencies: Vec<String>,
+    },
+}
+
+const DENO_CONFIG_FILES: &[&str] = &["deno.json", "deno.jsonc"];
+
+/// Lock files and their tools, in the order in which they are checked.
+const LOCK_FILES: &[(&str, &str)] = &[
+    ("bun.lockb", "bun"),
+    ("bun.lock", "bun"),
+    ("pnpm-lock.yaml", "pnpm"),
+    ("yarn.lock", "yarn"),
+    ("package-lock.json", "npm"),
+    ("deno.lock", "deno"),
+];
+
 impl NodePackageManager {
+    /// Reads the Corepack `packageManager` field of package.json, such as
+    /// `pnpm@9.1.0+sha512.abc`, returning the tool and its version.
+    fn from_package_manager_field(dir: &Path) -> Option<(Self, String)> {
+        let content = fs::read_to_string(dir.join("package.json")).ok()?;
+        let package: serde_json::Value = serde_json::from_str(&content).ok()?;
+        let (tool, version) = package.get("packageManager")?.as_str()?.split_once('@')?;
+        let package_manager = match tool {
+            "npm" => Self::Npm,
+            "yarn" => Self::Yarn,
+            "pnpm" => Self::Pnpm,
+            "bun" => Self::Bun,
+            _ => return None,
+        };
+        let version = version.split('+').next().unwrap_or(version);
+        Some((package_manager, version.to_string()))
+    }
+
+    /// Infers the tool from lock files, then from configuration files.
+    fn from_project_files(dir: &Path) -> Option<Self> {
+        if dir.join("bun.lockb").exists() || dir.join("bun.lock").exists() {
+            Some(Self::Bun)
+        } else if dir.join("pnpm-lock.yaml").exists() {
+            Some(Self::Pnpm)
+        } else if dir.join("yarn.lock").exists() {
+            Some(Self::Yarn)
+        } else if dir.join("package-lock.json").exists() {
+            Some(Self::Npm)
+        } else if dir.join("deno.lock").exists() || find_deno_config(dir).is_some() {
+            Some(Self::Deno)
+        } else if fs::read_to_string(dir.join(".npmrc")).is_ok_and(|c| c.contains("pnpm")) {
+            Some(Self::Pnpm)
+        } else if dir.join(".npmrc").exists() {
+            Some(Self::Npm)
+        } else if dir.join(".yarnrc").exists() || dir.join(".yarnrc.yml").exists() {
+            Some(Self::Yarn)
+        } else {
+            None
+        }
+    }
+
+    fn from_package_dir(dir: &Path) -> Option<Self> {
+        Self::from_package_manager_field(dir)
+            .map(|(package_manager, _)| package_manager)
+            .or_else(|| Self::from_project_files(dir))
+    }
+
     fn detect_script_type(&self, name: &str, command: &str) -> ScriptType {
         let text = format!("{} {}", name, command).to_lowercase();
 
@@ -73,39 +186,267 @@ impl NodePackageManager {
             .map(|(_, script_type)| *script_type)
             .unwrap_or(ScriptType::Other)
     }
+
+    fn parse_package_json_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let content = fs::read_to_string(path.join("package.json"))?;
+        let package: PackageJson = serde_json::from_str(&content)?;
+
+        Ok(package
+            .scripts
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, command)| {
+                Script::new(
+                    &name,
+                    &command,
+                    package.descriptions.get(&name).cloned(),
+                    Some(self.detect_script_type(&name, &command)),
+                    None,
+                )
+            })
+            .collect())
+    }
+
+    fn parse_deno_tasks(&self, config_path: &Path) -> Result<Vec<Script>> {
+        let content = fs::read_to_string(config_path)?;
+        let deno: DenoJson = serde_json::from_str(&strip_jsonc(&content))?;
+
+        Ok(deno
+            .tasks
+            .into_iter()
+            .map(|(name, task)| {
+                let (command, description) = match task {
+                    DenoTask::Command(command) => (command, None),
+                    DenoTask::Definition {
+                        command,
+                        description,
+                        dependencies,
+                    } => {
+                        let description = match (description, dependencies.is_empty()) {
+                            (description, true) => description,
+                            (Some(description), false) => Some(format!(
+                                "{} (depends on: {})",
+                                description,
+                                dependencies.join(", ")
+                            )),
+                            (None, false) => {
+                                Some(format!("Depends on: {}", dependencies.join(", ")))
+                            }
+                        };
+                        // Dependency-only tasks have no command of their own
+                        let command = command.unwrap_or_else(|| format!("deno task {}", name));
+                        (command, description)
+                    }
+                };
+                Script::new(
+                    &name,
+                    &command,
+                    description,
+                    Some(self.detect_script_type(&name, &command)),
+                    None,
+                )
+            })
+            .collect())
+    }
+
+    /// Returns the globs that locate workspace members: the `packages` of
+    /// `pnpm-workspace.yaml`, or the `workspaces` field of package.json.
+    fn workspace_patterns(&self, path: &Path) -> Vec<String> {
+        if let Ok(content) = fs::read_to_string(path.join("pnpm-workspace.yaml")) {
+            return serde_yaml::from_str::<PnpmWorkspace>(&content)
+                .map(|workspace| workspace.packages)
+                .unwrap_or_default();
+        }
+        fs::read_to_string(path.join("package.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<PackageJson>(&content).ok())
+            .and_then(|package| package.workspaces)
+            .map(|workspaces| match workspaces {
+                Workspaces::Paths(paths) => paths,
+                Workspaces::Config { packages } => packages,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the workspace's member packages. Patterns starting with `!`
+    /// exclude directories.
+    fn find_workspace_members(&self, path: &Path) -> Vec<WorkspaceMember> {
+        let patterns = self.workspace_patterns(path);
+        let excluded: Vec<PathBuf> = patterns
+            .iter()
+            .filter_map(|pattern| pattern.strip_prefix('!'))
+            .flat_map(|pattern| expand_workspace_pattern(path, pattern))
+            .collect();
+
+        let mut members: Vec<WorkspaceMember> = Vec::new();
+        for pattern in patterns.iter().filter(|p| !p.starts_with('!')) {
+            for dir in expand_workspace_pattern(path, pattern) {
+                if dir == path || excluded.contains(&dir) || members.iter().any(|m| m.path == dir)
+                {
+                    continue;
+                }
+                let Some(package) = fs::read_to_string(dir.join("package.json"))
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<PackageJson>(&content).ok())
+                else {
+                    continue;
+                };
+                let name = package.name.unwrap_or_else(|| {
+                    dir.file_name().unwrap_or_default().to_string_lossy().into_owned()
+                });
+                let mut dependencies: Vec<String> = package
+                    .dependencies
+                    .into_keys()
+                    .chain(package.dev_dependencies.into_keys())
+                    .collect();
+                dependencies.sort();
+                members.push(WorkspaceMember {
+                    name,
+                    path: dir,
+                    package_manager: Box::new(*self),
+                    dependencies,
+                });
+            }
+        }
+        members
+    }
+
+    /// Returns the command that runs a member package's script from the
+    /// workspace root.
+    fn member_command(&self, package: &str, script: &str) -> String {
+        match self {
+            Self::Npm => format!("npm -w {} run {}", package, script),
+            Self::Yarn => format!("yarn workspace {} run {}", package, script),
+            Self::Pnpm => format!("pnpm --filter {} run {}", package, script),
+            Self::Bun => format!("bun run --filter {} {}", package, script),
+            Self::Deno => format!("deno task --filter {} {}", package, script),
+        }
+    }
+
+    /// Returns the command that runs `command`, which invokes the binary of
+    /// a locally installed package such as turbo, without it being on PATH.
+    fn exec_command(&self, command: &str) -> String {
+        match self {
+            Self::Npm => format!("npx {}", command),
+            Self::Yarn => format!("yarn {}", command),
+            Self::Pnpm => format!("pnpm exec {}", command),
+            Self::Bun => format!("bunx {}", command),
+            Self::Deno => format!("deno run -A npm:{}", command),
+        }
+    }
+}
+
+/// Returns the directories above `dir` that may be its workspace root, up to
+/// the first that is a workspace or repository root, so that a stray
+/// package.json or lock file in a home directory doesn't decide the tool of
+/// every project below it.
+fn workspace_root_candidates(dir: &Path) -> impl Iterator<Item = &Path> {
+    let mut at_boundary = is_workspace_boundary(dir);
+    dir.ancestors().skip(1).take_while(move |ancestor| {
+        let is_candidate = !at_boundary;
+        at_boundary = at_boundary || is_workspace_boundary(ancestor);
+        is_candidate
+    })
+}
+
+/// Returns true if `dir` is the root of a workspace or of a repository.
+fn is_workspace_boundary(dir: &Path) -> bool {
+    dir.join(".git").exists()
+        || dir.join("pnpm-workspace.yaml").exists()
+        || fs::read_to_string(dir.join("package.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<PackageJson>(&content).ok())
+            .is_some_and(|package| package.workspaces.is_some())
+}
+
+fn find_deno_config(dir: &Path) -> Option<PathBuf> {
+    DENO_CONFIG_FILES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// Converts JSONC (JSON with comments and trailing commas, as accepted in
+/// `deno.jsonc`) into plain JSON.
+pub(super) fn strip_jsonc(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => output.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(c);
+            }
+            ('/', Some('/')) => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            (']' | '}', _) => {
+                // Drop a trailing comma before the closing bracket
+                let trimmed_len = output.trim_end().len();
+                if output[..trimmed_len].ends_with(',') {
+                    output.truncate(trimmed_len - 1);
+                }
+                output.push(c);
+            }
+            _ => output.push(c),
+        }
+    }
+
+    output
 }
 
 impl PackageManager for NodePackageManager {
     fn detect(dir: &Path) -> Option<Self> {
         if !dir.join("package.json").exists() {
-            return None;
+            // Pure Deno projects have no package.json
+            return find_deno_config(dir).map(|_| Self::Deno);
         }
-        // Check lock files first
-        if dir.join("bun.lockb").exists() {
-            return Some(Self::Bun);
-        } else if dir.join("pnpm-lock.yaml").exists() {
-            return Some(Self::Pnpm);
-        } else if dir.join("yarn.lock").exists() {
-            return Some(Self::Yarn);
-        } else if dir.join("package-lock.json").exists() {
-            return Some(Self::Npm);
-        } else if dir.join("deno.lock").exists() {
-            return Some(Self::Deno);
+        // The packageManager field names the tool outright; otherwise check
+        // the lock and configuration files
+        if let Some(package_manager) = Self::from_package_dir(dir) {
+            return Some(package_manager);
         }
 
-        // Check config files as fallback
-        if dir.join(".npmrc").exists() {
-            return Some(Self::Npm);
-        } else if dir.join(".yarnrc").exists() || dir.join(".yarnrc.yml").exists() {
-            return Some(Self::Yarn);
-        } else if dir.join(".npmrc").exists()
-            && std::fs::read_to_string(dir.join(".npmrc"))
-                .map_or(false, |content| content.contains("pnpm"))
-        {
-            return Some(Self::Pnpm);
-        }
+        // Workspace members use the tool of the workspace root
+        let workspace_root = workspace_root_candidates(dir)
+            .filter(|ancestor| ancestor.join("package.json").exists())
+            .find_map(Self::from_package_dir);
+
+        // Projects without a lock file, such as fresh clones, default to npm
+        Some(workspace_root.unwrap_or(Self::Npm))
+    }
 
-        None
+    fn tool_name(&self) -> &'static str {
+        match self {
+            Self::Npm => "npm",
+            Self::Yarn => "yarn",
+            Self::Pnpm => "pnpm",
+            Self::Bun => "bun",
+            Self::Deno => "deno",
+        }
     }
 
     fn run_command(&self, script: &str) -> Command {
@@ -141,46 +482,138 @@ impl PackageManager for NodePackageManager {
     }
 
     fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
-        let package_json_path = path.join("package.json");
-        if !package_json_path.exists() {
+        let deno_config = match self {
+            Self::Deno => find_deno_config(path),
+            _ => None,
+        };
+        if !path.join("package.json").exists() && deno_config.is_none() {
             return Err(anyhow::anyhow!("package.json not found"));
         }
-        let content = fs::read_to_string(package_json_path)?;
-        let package: PackageJson = serde_json::from_str(&content)?;
 
+        // First collect all scripts. `deno task` also runs package.json
+        // scripts, but deno.json tasks take precedence.
         let mut scripts = Vec::new();
-        if let Some(script_map) = package.scripts {
-            // First collect all scripts
-            let mut all_scripts: Vec<_> = script_map
-                .into_iter()
-                .map(|(name, command)| {
+        if let Some(config_path) = &deno_config {
+            scripts.extend(self.parse_deno_tasks(config_path)?);
+        }
+        if path.join("package.json").exists() {
+            for script in self.parse_package_json_scripts(path)? {
+                if !scripts.iter().any(|s: &Script| s.name == script.name) {
+                    scripts.push(script);
+                }
+            }
+        }
+
+        // Add the scripts of workspace members, namespaced by package name
+        for member in self.find_workspace_members(path) {
+            let package = member.name;
+            let Ok(member_scripts) = self.parse_package_json_scripts(&member.path) else {
+                continue;
+            };
+            for script in member_scripts {
+                scripts.push(
                     Script::new(
-                        &name,
-                        &command,
-                        package.descriptions.get(&name).cloned(),
-                        Some(self.detect_script_type(&name, &command)),
+                        &format!("{}:{}", package, script.name),
+                        &self.member_command(&package, &script.name),
+                        script.description,
+                        Some(script.script_type),
                         None,
                     )
-                })
-                .collect();
-
-            // Sort scripts: non-Other types first (alphabetically), then Other types (alphabetically)
-            all_scripts.sort_by(|a, b| match (a.phase, b.phase) {
-                (Phase::Unknown, Phase::Unknown) => a.name.cmp(&b.name),
-                (Phase::Unknown, _) => std::cmp::Ordering::Greater,
-                (_, Phase::Unknown) => std::cmp::Ordering::Less,
-                _ => a.name.cmp(&b.name),
-            });
+                    .with_package(&package),
+                );
+            }
+        }
 
-            scripts.extend(all_scripts);
+        // Add the tasks of Turborepo and Nx, which run through their own CLIs.
+        // These are usually dev dependencies, so they run through the
+        // package manager. A malformed turbo.json or nx.json is reported by
+        // `warnings` rather than hiding the package's scripts.
+        let pipeline_tasks = find_turbo_tasks(path)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|task| (Pipeline::Turbo, task))
+            .chain(
+                find_nx_targets(path)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|task| (Pipeline::Nx, task)),
+            );
+        for (pipeline, task) in pipeline_tasks {
+            scripts.push(
+                Script::new(
+                    &format!("{}:{}", pipeline.tool_name(), task.name),
+                    &self.exec_command(&task.command),
+                    task.description,
+                    Some(self.detect_script_type(&task.name, "")),
+                    None,
+                )
+                .with_depends_on(task.depends_on)
+                .with_pipeline(pipeline),
+            );
         }
+
+        // Sort scripts: non-Other types first (alphabetically), then Other types (alphabetically)
+        scripts.sort_by(|a, b| match (a.phase, b.phase) {
+            (Phase::Unknown, Phase::Unknown) => a.name.cmp(&b.name),
+            (Phase::Unknown, _) => std::cmp::Ordering::Greater,
+            (_, Phase::Unknown) => std::cmp::Ordering::Less,
+            _ => a.name.cmp(&b.name),
+        });
+
         Ok(scripts)
     }
+
+    fn script_command(&self, script: &Script) -> Command {
+        // Member scripts select their package with the filter flag, and
+        // Turborepo and Nx tasks run through their own CLIs
+        if script.package.is_some() || script.pipeline.is_some() {
+            shell_command(&script.command)
+        } else {
+            self.run_command(&script.name)
+        }
+    }
+
+    fn workspace_members(&self, path: &Path) -> Vec<WorkspaceMember> {
+        self.find_workspace_members(path)
+    }
+
+    fn warnings(&self, path: &Path) -> Vec<Warning> {
+        let reason = if Self::from_package_manager_field(path).is_some() {
+            format!("The packageManager field of package.json selects {}", self.tool_name())
+        } else {
+            "psr checks lock files in the order bun, pnpm, yarn, npm, deno".to_string()
+        };
+        let mut warnings: Vec<Warning> =
+            lock_file_conflict(path, LOCK_FILES, self.tool_name(), &reason)
+                .into_iter()
+                .collect();
+        if let Err(error) = find_turbo_tasks(path) {
+            warnings.push(unparsable_config("turbo.json", &error));
+        }
+        if let Err(error) = find_nx_targets(path) {
+            warnings.push(unparsable_config("nx.json", &error));
+        }
+        warnings
+    }
+
+    fn tool_description(&self, path: &Path) -> Option<String> {
+        let version = std::iter::once(path)
+            .chain(workspace_root_candidates(path))
+            .filter(|dir| dir.join("package.json").exists())
+            .find_map(Self::from_package_manager_field)
+            .filter(|(package_manager, _)| package_manager == self)
+            .map(|(_, version)| version);
+        Some(match version {
+            Some(version) => format!("{} {}", self.tool_name(), version),
+            None => self.tool_name().to_string(),
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::project_dir_mocks::*;
 
     #[test]
     fn test_detect_script_type() {
@@ -192,4 +625,153 @@ mod tests {
         assert_eq!(npm.detect_script_type("format", "prettier --write ."), ScriptType::Format);
         assert_eq!(npm.detect_script_type("typecheck", "tsc"), ScriptType::TypeCheck);
     }
+
+    #[test]
+    fn test_find_deno_tasks() {
+        let temp_dir = create_deno_project(&std::env::temp_dir().join("deno-project")).unwrap();
+        let deno = NodePackageManager::detect(&temp_dir.dir).unwrap();
+        assert!(matches!(deno, NodePackageManager::Deno));
+
+        let scripts = deno.find_scripts(&temp_dir.dir).unwrap();
+        assert!(scripts.iter().any(|s| s.name == "dev"
+            && s.command == "deno run --watch main.ts"
+            && s.script_type == ScriptType::Serve));
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "deno test"
+            && s.description.as_deref() == Some("Run the tests (depends on: check)")));
+        assert!(scripts.iter().any(|s| s.name == "ci"
+            && s.command == "deno task ci"
+            && s.description.as_deref() == Some("Depends on: check, test")));
+    }
+
+    #[test]
+    fn test_find_workspace_scripts() {
+        let temp_dir =
+            create_npm_workspace_project(&std::env::temp_dir().join("npm-workspace")).unwrap();
+        let npm = NodePackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = npm.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "lint" && s.command == "eslint ." && s.package.is_none()));
+        assert!(scripts.iter().any(|s| s.name == "@acme/web:build"
+            && s.command == "npm -w @acme/web run build"
+            && s.script_type == ScriptType::Build
+            && s.package.as_deref() == Some("@acme/web")));
+        assert!(scripts.iter().any(|s| s.name == "api:test"
+            && s.command == "npm -w api run test"
+            && s.script_type == ScriptType::Test));
+        // Negated patterns exclude members
+        assert!(!scripts.iter().any(|s| s.name.starts_with("legacy:")));
+
+        let members: Vec<String> = npm
+            .workspace_members(&temp_dir.dir)
+            .into_iter()
+            .map(|member| member.name)
+            .collect();
+        assert_eq!(members, ["api", "@acme/web"]);
+        assert_eq!(
+            NodePackageManager::Pnpm.member_command("api", "test"),
+            "pnpm --filter api run test"
+        );
+    }
+
+    #[test]
+    fn test_find_pipeline_tasks() {
+        let temp_dir =
+            create_turbo_nx_project(&std::env::temp_dir().join("turbo-nx-project")).unwrap();
+        let npm = NodePackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = npm.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "turbo:build"
+            && s.command == "npx turbo run build"
+            && s.script_type == ScriptType::Build
+            && s.depends_on == ["^build"]));
+        assert!(scripts.iter().any(|s| s.name == "turbo:web#deploy"
+            && s.command == "npx turbo run web#deploy"
+            && s.depends_on == ["web#build", "test"]));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "turbo:lint" && s.command == "npx turbo run lint"));
+        // Targets without dependsOn use the nx.json target defaults
+        assert!(scripts.iter().any(|s| s.name == "nx:web:build"
+            && s.command == "npx nx run web:build"
+            && s.pipeline == Some(Pipeline::Nx)
+            && s.depends_on == ["^build"]));
+        assert!(scripts.iter().any(|s| s.name == "nx:web:serve"
+            && s.description.as_deref() == Some("Serve the web app")
+            && s.depends_on == ["^codegen"]));
+
+        let turbo_build = scripts.iter().find(|s| s.name == "turbo:build").unwrap();
+        let command = npm.script_command(turbo_build);
+        assert_eq!(command.get_program(), "sh");
+        assert_eq!(command.get_args().nth(1).unwrap(), "npx turbo run build \"$@\"");
+        assert_eq!(
+            NodePackageManager::Pnpm.exec_command("nx run web:build"),
+            "pnpm exec nx run web:build"
+        );
+    }
+
+    #[test]
+    fn test_malformed_turbo_json() {
+        let temp_dir =
+            create_npm_project(&std::env::temp_dir().join("malformed-turbo-project")).unwrap();
+        temp_dir.create_file("turbo.json", "{ \"tasks\": ").unwrap();
+        let npm = NodePackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = npm.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "test"));
+        assert!(!scripts.iter().any(|s| s.pipeline.is_some()));
+        let warnings = npm.warnings(&temp_dir.dir);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("turbo.json"));
+    }
+
+    #[test]
+    fn test_detect_package_manager_field() {
+        let temp_dir = create_corepack_project(&std::env::temp_dir().join("corepack")).unwrap();
+
+        let pinned = NodePackageManager::detect(&temp_dir.dir.join("pinned")).unwrap();
+        assert_eq!(pinned, NodePackageManager::Pnpm);
+        assert_eq!(
+            pinned.tool_description(&temp_dir.dir.join("pinned")).as_deref(),
+            Some("pnpm 9.1.0")
+        );
+
+        // Workspace members use the workspace root's tool
+        let member_dir = temp_dir.dir.join("pinned/packages/ui");
+        let member = NodePackageManager::detect(&member_dir).unwrap();
+        assert_eq!(member, NodePackageManager::Pnpm);
+        assert_eq!(member.tool_description(&member_dir).as_deref(), Some("pnpm 9.1.0"));
+
+        let plain = NodePackageManager::detect(&temp_dir.dir.join("plain")).unwrap();
+        assert_eq!(plain, NodePackageManager::Npm);
+        assert_eq!(
+            plain.tool_description(&temp_dir.dir.join("plain")).as_deref(),
+            Some("npm")
+        );
+    }
+
+    #[test]
+    fn test_lock_file_conflict() {
+        let temp_dir =
+            create_lock_conflict_project(&std::env::temp_dir().join("lock-conflict")).unwrap();
+        let dir = temp_dir.dir.join("node");
+        let yarn = NodePackageManager::detect(&dir).unwrap();
+        assert_eq!(yarn, NodePackageManager::Yarn);
+
+        let warnings = yarn.warnings(&dir);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Conflicting lock files yarn.lock, package-lock.json; using yarn"
+        );
+        assert!(warnings[0].explanation.contains("`rm package-lock.json`"));
+
+        let plain = create_corepack_project(&std::env::temp_dir().join("lock-conflict-none"))
+            .unwrap()
+            .dir
+            .join("plain");
+        assert!(NodePackageManager::Npm.warnings(&plain).is_empty());
+    }
 }
@@ -0,0 +1,184 @@
+use anyhow::Result;
+
+use std::{fs, path::Path, process::Command};
+
+use super::PackageManager;
+use crate::types::{Script, ScriptType};
+
+pub struct NoxPackageManager;
+
+struct NoxSession {
+    name: String,
+    docstring: Option<String>,
+    body: String,
+}
+
+impl PackageManager for NoxPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("noxfile.py").exists() {
+            Some(NoxPackageManager)
+        } else {
+            None
+        }
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "nox"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("nox");
+        cmd.arg("-s").arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let content = fs::read_to_string(path.join("noxfile.py"))?;
+
+        Ok(parse_sessions(&content)
+            .into_iter()
+            .map(|session| {
+                Script::new(
+                    &session.name,
+                    &format!("nox -s {}", session.name),
+                    session.docstring,
+                    Some(ScriptType::from_script(&session.name, &session.body)),
+                    None,
+                )
+            })
+            .collect())
+    }
+}
+
+/// Finds the functions decorated with `@nox.session` (or `@session`), using
+/// the decorator's `name=` argument when present and the first line of the
+/// function's docstring as its description, as `nox --list` does.
+fn parse_sessions(content: &str) -> Vec<NoxSession> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut sessions = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+        i += 1;
+        if !(line.starts_with("@nox.session") || line.starts_with("@session")) {
+            continue;
+        }
+
+        // Collect the whole decorator, which may span several lines
+        let mut decorator = line.to_string();
+        while paren_depth(&decorator) > 0 && i < lines.len() {
+            decorator.push(' ');
+            decorator.push_str(lines[i].trim());
+            i += 1;
+        }
+
+        // Skip any further decorators to reach the function definition
+        while i < lines.len() && !lines[i].trim_start().starts_with("def ") {
+            i += 1;
+        }
+        let Some(def_line) = lines.get(i) else {
+            break;
+        };
+        let function_name = def_line
+            .trim_start()
+            .trim_start_matches("def ")
+            .split('(')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        let indent = def_line.len() - def_line.trim_start().len();
+        i += 1;
+
+        // Skip the rest of a multi-line signature
+        while i < lines.len() && !lines[i - 1].trim_end().ends_with(':') {
+            i += 1;
+        }
+
+        let body_start = i;
+        while i < lines.len()
+            && (lines[i].trim().is_empty() || lines[i].len() - lines[i].trim_start().len() > indent)
+        {
+            i += 1;
+        }
+        let body = &lines[body_start..i];
+
+        sessions.push(NoxSession {
+            name: keyword_argument(&decorator, "name").unwrap_or_else(|| function_name.to_string()),
+            docstring: docstring(body),
+            body: body.join("\n"),
+        });
+    }
+
+    sessions
+}
+
+fn paren_depth(text: &str) -> i32 {
+    text.chars().fold(0, |depth, c| match c {
+        '(' => depth + 1,
+        ')' => depth - 1,
+        _ => depth,
+    })
+}
+
+/// Returns the string value of a keyword argument such as `name="tests"`.
+fn keyword_argument(call: &str, keyword: &str) -> Option<String> {
+    let start = call.find(&format!("{}=", keyword))? + keyword.len() + 1;
+    let value = call[start..].trim_start();
+    let quote = value.chars().next().filter(|&c| c == '"' || c == '\'')?;
+    let value = &value[1..];
+    value.find(quote).map(|end| value[..end].to_string())
+}
+
+/// Returns the first line of the docstring at the start of a function body.
+fn docstring(body: &[&str]) -> Option<String> {
+    let first = body
+        .iter()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty())?;
+    let first = first.trim_start_matches(['r', 'u']);
+    let first = first
+        .strip_prefix("\"\"\"")
+        .or_else(|| first.strip_prefix("'''"))?;
+    let summary = first
+        .trim_end_matches("\"\"\"")
+        .trim_end_matches("'''")
+        .trim();
+    if summary.is_empty() {
+        // The summary is on the line after the opening quotes
+        body.iter()
+            .map(|line| line.trim())
+            .skip_while(|line| line.is_empty())
+            .nth(1)
+            .map(|line| line.trim_end_matches("\"\"\"").trim().to_string())
+            .filter(|line| !line.is_empty())
+    } else {
+        Some(summary.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir = create_nox_project(&std::env::temp_dir().join("nox-project")).unwrap();
+        let nox = NoxPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = nox.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(names, ["tests", "lint", "docs"]);
+        assert!(scripts.iter().any(|s| s.name == "tests"
+            && s.command == "nox -s tests"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Run the test suite.")));
+        assert!(scripts.iter().any(|s| s.name == "lint"
+            && s.script_type == ScriptType::Lint
+            && s.description.as_deref() == Some("Lint using ruff.")));
+        assert!(scripts.iter().any(|s| s.name == "docs"
+            && s.script_type == ScriptType::Build
+            && s.description.is_none()));
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::Result;
+use serde::Deserialize;
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+    process::Command,
+};
+
+use super::PackageManager;
+use crate::types::{Script, ScriptType};
+
+/// `@`-prefixed commands that Composer provides, rather than references to
+/// other scripts.
+const BUILTIN_REFERENCES: &[&str] = &["php", "composer", "putenv"];
+
+pub struct PhpPackageManager;
+
+#[derive(Deserialize)]
+struct ComposerJson {
+    #[serde(default)]
+    scripts: BTreeMap<String, ComposerScript>,
+    #[serde(default, rename = "scripts-descriptions")]
+    scripts_descriptions: HashMap<String, String>,
+    #[serde(default, rename = "scripts-aliases")]
+    scripts_aliases: HashMap<String, Vec<String>>,
+}
+
+/// A script in composer.json: a command, or a list of commands run in turn.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ComposerScript {
+    Command(String),
+    Commands(Vec<String>),
+}
+
+impl ComposerScript {
+    fn commands(&self) -> Vec<&str> {
+        match self {
+            Self::Command(command) => vec![command.as_str()],
+            Self::Commands(commands) => commands.iter().map(|c| c.as_str()).collect(),
+        }
+    }
+}
+
+impl PackageManager for PhpPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("composer.json").exists() {
+            Some(PhpPackageManager)
+        } else {
+            None
+        }
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "composer"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("composer");
+        cmd.arg("run-script").arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let content = fs::read_to_string(path.join("composer.json"))?;
+        let composer: ComposerJson = serde_json::from_str(&content)?;
+
+        Ok(composer
+            .scripts
+            .iter()
+            .map(|(name, script)| {
+                let commands = script.commands();
+                let references: Vec<String> = commands
+                    .iter()
+                    .filter_map(|command| script_reference(command))
+                    .filter(|reference| composer.scripts.contains_key(*reference))
+                    .map(|reference| reference.to_string())
+                    .collect();
+                let command = commands.join(" && ");
+                Script::new(
+                    name,
+                    &command,
+                    composer.scripts_descriptions.get(name).cloned(),
+                    Some(detect_script_type(name, &command)),
+                    None,
+                )
+                .with_aliases(
+                    composer
+                        .scripts_aliases
+                        .get(name)
+                        .cloned()
+                        .unwrap_or_default(),
+                )
+                .with_depends_on(references)
+            })
+            .collect())
+    }
+}
+
+/// Returns the script that a command such as `@test` runs, if it refers to
+/// one rather than to a built-in such as `@php`.
+fn script_reference(command: &str) -> Option<&str> {
+    let reference = command.strip_prefix('@')?.split_whitespace().next()?;
+    (!BUILTIN_REFERENCES.contains(&reference)).then_some(reference)
+}
+
+/// Classifies a script by the PHP tools it runs, falling back to its name.
+fn detect_script_type(name: &str, command: &str) -> ScriptType {
+    const PATTERNS: &[(&[&str], ScriptType)] = &[
+        (&["phpunit", "pest", "codecept"], ScriptType::Test),
+        (&["phpstan", "psalm"], ScriptType::TypeCheck),
+        (&["php-cs-fixer", "pint", "phpcbf"], ScriptType::Format),
+        (&["phpcs", "phpmd", "rector"], ScriptType::Lint),
+        (&["artisan serve", "php -s"], ScriptType::Serve),
+        (
+            &["artisan migrate", "doctrine:migrations"],
+            ScriptType::Migration,
+        ),
+    ];
+
+    let command = command.to_lowercase();
+    PATTERNS
+        .iter()
+        .find(|(patterns, _)| patterns.iter().any(|p| command.contains(p)))
+        .map(|(_, script_type)| *script_type)
+        .unwrap_or_else(|| ScriptType::from_script(name, &command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir =
+            create_composer_project(&std::env::temp_dir().join("composer-project")).unwrap();
+        let composer = PhpPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = composer.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            ["analyse", "check", "cs-fix", "post-install-cmd", "test"]
+        );
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "phpunit --colors=always"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Run the PHPUnit test suite")
+            && s.is_named("t")));
+        assert!(scripts.iter().any(|s| s.name == "analyse"
+            && s.script_type == ScriptType::TypeCheck
+            && s.description.is_none()));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "cs-fix" && s.script_type == ScriptType::Format));
+        assert!(scripts.iter().any(|s| s.name == "check"
+            && s.command == "@cs-fix --dry-run && @analyse && @test"
+            && s.depends_on == ["cs-fix", "analyse", "test"]));
+        assert!(scripts.iter().any(|s| s.name == "post-install-cmd"
+            && s.command == "@php artisan key:generate"
+            && s.depends_on.is_empty()));
+
+        let command = composer.script_command(&scripts[0]);
+        assert_eq!(command.get_program(), "composer");
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            ["run-script", "analyse"]
+        );
+    }
+}
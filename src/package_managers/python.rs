@@ -1,14 +1,28 @@
 use anyhow::Result;
 use toml::Value;
 
-use std::{fs, path::Path, process::Command};
+use std::{collections::HashSet, fs, path::Path, process::Command};
 
-use super::PackageManager;
+use super::diagnostics::lock_file_conflict;
+use super::{shell_command, PackageManager, Warning};
 use crate::types::{Script, ScriptType};
 
+/// Lock files and their tools, in the order in which `detect` checks them
+/// when pyproject.toml doesn't configure a tool.
+const LOCK_FILES: &[(&str, PythonPackageManager)] = &[
+    ("poetry.lock", PythonPackageManager::Poetry),
+    ("pdm.lock", PythonPackageManager::Pdm),
+    ("uv.lock", PythonPackageManager::Uv),
+    ("requirements.lock", PythonPackageManager::Rye),
+];
+
+#[derive(Clone, Copy)]
 pub enum PythonPackageManager {
     Pip,
     Poetry,
+    Pdm,
+    Hatch,
+    Rye,
     Uv,
 }
 
@@ -23,6 +37,18 @@ impl PackageManager for PythonPackageManager {
                         .is_some()
                     {
                         return Some(Self::Poetry);
+                    } else if pyproject.get("tool").and_then(|t| t.get("pdm")).is_some() {
+                        return Some(Self::Pdm);
+                    } else if pyproject.get("tool").and_then(|t| t.get("rye")).is_some() {
+                        // Checked before Hatch: Rye projects often configure hatchling
+                        return Some(Self::Rye);
+                    } else if pyproject
+                        .get("tool")
+                        .and_then(|t| t.get("hatch"))
+                        .and_then(|h| h.get("envs"))
+                        .is_some()
+                    {
+                        return Some(Self::Hatch);
                     } else if pyproject.get("tool").and_then(|t| t.get("uv")).is_some()
                         || pyproject
                             .get("build-system")
@@ -40,8 +66,12 @@ impl PackageManager for PythonPackageManager {
                 }
             }
         }
-        if dir.join("poetry.lock").exists() {
-            Some(Self::Poetry)
+        if let Some((_, package_manager)) =
+            LOCK_FILES.iter().find(|(file, _)| dir.join(file).exists())
+        {
+            Some(*package_manager)
+        } else if dir.join("hatch.toml").exists() {
+            Some(Self::Hatch)
         } else if dir.join(".uv").exists() || dir.join("uv.toml").exists() {
             Some(Self::Uv)
         } else if dir.join("requirements.txt").exists() {
@@ -51,6 +81,17 @@ impl PackageManager for PythonPackageManager {
         }
     }
 
+    fn tool_name(&self) -> &'static str {
+        match self {
+            Self::Pip => "pip",
+            Self::Poetry => "poetry",
+            Self::Pdm => "pdm",
+            Self::Hatch => "hatch",
+            Self::Rye => "rye",
+            Self::Uv => "uv",
+        }
+    }
+
     fn run_command(&self, script: &str) -> Command {
         match self {
             Self::Pip => {
@@ -65,6 +106,24 @@ impl PackageManager for PythonPackageManager {
                 cmd.arg(script);
                 cmd
             }
+            Self::Pdm => {
+                let mut cmd = Command::new("pdm");
+                cmd.arg("run");
+                cmd.arg(script);
+                cmd
+            }
+            Self::Hatch => {
+                let mut cmd = Command::new("hatch");
+                cmd.arg("run");
+                cmd.arg(script);
+                cmd
+            }
+            Self::Rye => {
+                let mut cmd = Command::new("rye");
+                cmd.arg("run");
+                cmd.arg(script);
+                cmd
+            }
             Self::Uv => {
                 let mut cmd = Command::new("uv");
                 cmd.arg("run");
@@ -75,11 +134,76 @@ impl PackageManager for PythonPackageManager {
     }
 
     fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
-        match self {
+        let mut scripts = match self {
             Self::Pip => self.parse_pip_scripts(path),
             Self::Poetry => self.parse_poetry_scripts(path),
+            Self::Pdm => self.parse_pdm_scripts(path),
+            Self::Hatch => self.parse_hatch_scripts(path),
+            Self::Rye => self.parse_rye_scripts(path),
             Self::Uv => self.parse_uv_scripts(path),
-        }
+        }?;
+
+        // poe tasks are defined by the project, so they replace the scripts
+        // inferred from its dependencies
+        let poe_tasks = self.parse_poe_tasks(path)?;
+        scripts.retain(|script| !poe_tasks.iter().any(|task| task.name == script.name));
+        scripts.extend(poe_tasks);
+        Ok(scripts)
+    }
+
+    // Scripts mix entry points, tool invocations and task runners such as
+    // poe, so each script's own command is what runs it.
+    fn script_command(&self, script: &Script) -> Command {
+        shell_command(&script.command)
+    }
+
+    fn warnings(&self, path: &Path) -> Vec<Warning> {
+        let lock_files: Vec<(&str, &str)> = LOCK_FILES
+            .iter()
+            .map(|(file, package_manager)| (*file, package_manager.tool_name()))
+            .collect();
+        let order: Vec<&str> = lock_files.iter().map(|(_, tool)| *tool).collect();
+        let reason = format!(
+            "psr selects the tool configured in pyproject.toml, then checks lock files \
+             in the order {}",
+            order.join(", ")
+        );
+        lock_file_conflict(path, &lock_files, self.tool_name(), &reason)
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Describes the steps of a poe `sequence` task, which are task references or
+/// inline tasks such as `{ cmd = "..." }`.
+fn poe_sequence_steps(steps: &[Value]) -> Vec<String> {
+    steps
+        .iter()
+        .filter_map(|step| match step {
+            Value::String(s) => Some(s.clone()),
+            _ => ["ref", "cmd", "script", "shell"]
+                .iter()
+                .find_map(|key| step.get(key).and_then(|v| v.as_str()))
+                .map(|s| s.to_string()),
+        })
+        .collect()
+}
+
+/// Returns the string elements of a TOML array.
+fn string_values(values: &[Value]) -> Vec<String> {
+    values
+        .iter()
+        .filter_map(|v| v.as_str())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Reads a command given either as a string or as an array of arguments.
+fn command_value(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(s) => Some(s.clone()),
+        Value::Array(args) => Some(string_values(args).join(" ")),
+        _ => None,
     }
 }
 
@@ -141,121 +265,46 @@ impl PythonPackageManager {
         let pyproject: toml::Value = toml::from_str(&content)?;
 
         let mut scripts = Vec::new();
+        let poetry = pyproject.get("tool").and_then(|t| t.get("poetry"));
 
         // Add common Python linting commands if the tools are in dependencies
-        if let Some(tool) = pyproject.get("tool") {
-            if let Some(poetry) = tool.get("poetry") {
-                if let Some(deps) = poetry
-                    .get("dependencies")
-                    .or_else(|| poetry.get("dev-dependencies"))
-                {
-                    let has_ruff = deps.as_table().map_or(false, |t| t.contains_key("ruff"));
-                    let has_flake8 = deps.as_table().map_or(false, |t| t.contains_key("flake8"));
-                    let has_pylint = deps.as_table().map_or(false, |t| t.contains_key("pylint"));
-
-                    if has_ruff {
-                        scripts.push(Script::new(
-                            "lint",
-                            "poetry run ruff check .",
-                            Some("Run Ruff linter".to_string()),
-                            Some(ScriptType::Lint),
-                            Some('l'),
-                        ));
-                    } else if has_flake8 {
-                        scripts.push(Script::new(
-                            "lint",
-                            "poetry run flake8",
-                            Some("Run Flake8 linter".to_string()),
-                            Some(ScriptType::Lint),
-                            Some('l'),
-                        ));
-                    } else if has_pylint {
-                        scripts.push(Script::new(
-                            "lint",
-                            "poetry run pylint **/*.py",
-                            Some("Run Pylint linter".to_string()),
-                            Some(ScriptType::Lint),
-                            Some('l'),
-                        ));
-                    }
-                }
-            }
-        }
-
-        if let Some(tool) = pyproject.get("tool") {
-            if let Some(poetry) = tool.get("poetry") {
-                if let Some(dependencies) = poetry.get("dependencies") {
-                    for (name, value) in dependencies.as_table().unwrap() {
-                        let command = if value.is_str() {
-                            format!("poetry add {}", name)
-                        } else {
-                            format!("poetry add {}@{}", name, value.as_str().unwrap_or("latest"))
-                        };
-                        scripts.push(Script::new(&name.to_string(), &command, None, None, None));
-                    }
-                }
-                if let Some(dev_dependencies) = poetry.get("dev-dependencies") {
-                    for (name, value) in dev_dependencies.as_table().unwrap() {
-                        let command = if value.is_str() {
-                            format!("poetry add --dev {}", name)
-                        } else {
-                            format!(
-                                "poetry add --dev {}@{}",
-                                name,
-                                value.as_str().unwrap_or("latest")
-                            )
-                        };
-                        scripts.push(Script::new(
-                            &format!("dev:{}", name),
-                            &command,
-                            None,
-                            None,
-                            None,
-                        ));
-                    }
-                }
-            }
-        }
+        if let Some(poetry) = poetry {
+            let dependency_tables = ["dependencies", "dev-dependencies"]
+                .iter()
+                .filter_map(|key| poetry.get(key))
+                .chain(
+                    poetry
+                        .get("group")
+                        .and_then(|g| g.as_table())
+                        .into_iter()
+                        .flat_map(|groups| groups.values())
+                        .filter_map(|group| group.get("dependencies")),
+                )
+                .filter_map(|deps| deps.as_table())
+                .collect::<Vec<_>>();
+            let has_dependency =
+                |name: &str| dependency_tables.iter().any(|t| t.contains_key(name));
 
-        Ok(scripts)
-    }
-
-    fn parse_uv_scripts(&self, path: &Path) -> Result<Vec<Script>> {
-        let uv_toml_path = path.join("uv.toml");
-        let content = fs::read_to_string(uv_toml_path)?;
-        let uv_config: toml::Value = toml::from_str(&content)?;
-        let mut scripts = Vec::new();
-
-        if let Some(dependencies) = uv_config.get("dependencies") {
-            // Create a new empty map that lives long enough
-            let empty_map = toml::map::Map::new();
-            // Use a reference to either the dependencies table or the empty map
-            let deps = dependencies.as_table().unwrap_or(&empty_map);
-
-            let has_ruff = deps.contains_key("ruff");
-            let has_flake8 = deps.contains_key("flake8");
-            let has_pylint = deps.contains_key("pylint");
-
-            if has_ruff {
+            if has_dependency("ruff") {
                 scripts.push(Script::new(
                     "lint",
-                    "uv run ruff check .",
+                    "poetry run ruff check .",
                     Some("Run Ruff linter".to_string()),
                     Some(ScriptType::Lint),
                     Some('l'),
                 ));
-            } else if has_flake8 {
+            } else if has_dependency("flake8") {
                 scripts.push(Script::new(
                     "lint",
-                    "uv run flake8",
+                    "poetry run flake8",
                     Some("Run Flake8 linter".to_string()),
                     Some(ScriptType::Lint),
                     Some('l'),
                 ));
-            } else if has_pylint {
+            } else if has_dependency("pylint") {
                 scripts.push(Script::new(
                     "lint",
-                    "uv run pylint **/*.py",
+                    "poetry run pylint **/*.py",
                     Some("Run Pylint linter".to_string()),
                     Some(ScriptType::Lint),
                     Some('l'),
@@ -263,8 +312,409 @@ impl PythonPackageManager {
             }
         }
 
+        // Entry points from [tool.poetry.scripts]
+        if let Some(entry_points) = poetry
+            .and_then(|p| p.get("scripts"))
+            .and_then(|s| s.as_table())
+        {
+            for (name, value) in entry_points {
+                let target = value
+                    .as_str()
+                    .or_else(|| value.get("callable").and_then(|c| c.as_str()))
+                    .or_else(|| value.get("reference").and_then(|r| r.as_str()));
+                scripts.push(Script::new(
+                    name,
+                    &format!("poetry run {}", name),
+                    target.map(|t| format!("Run the {} entry point", t)),
+                    Some(ScriptType::from_script(name, target.unwrap_or_default())),
+                    None,
+                ));
+            }
+        }
+
+        Ok(scripts)
+    }
+
+    /// Lists the poethepoet tasks from `[tool.poe.tasks]`, which may be plain
+    /// command strings, arrays (sequences) or tables with a task type key such
+    /// as `cmd`, `script`, `shell`, `sequence` or `ref`.
+    fn parse_poe_tasks(&self, path: &Path) -> Result<Vec<Script>> {
+        let pyproject: Value = match fs::read_to_string(path.join("pyproject.toml")) {
+            Ok(content) => toml::from_str(&content)?,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let Some(tasks) = pyproject
+            .get("tool")
+            .and_then(|t| t.get("poe"))
+            .and_then(|p| p.get("tasks"))
+            .and_then(|t| t.as_table())
+        else {
+            return Ok(Vec::new());
+        };
+
+        let scripts = tasks
+            .iter()
+            .map(|(name, task)| {
+                let help = task.get("help").and_then(|h| h.as_str());
+                let (content, description) = match task {
+                    Value::String(cmd) => (cmd.clone(), None),
+                    Value::Array(steps) => {
+                        let steps = poe_sequence_steps(steps);
+                        let description = format!("Runs in sequence: {}", steps.join(", "));
+                        (steps.join("\n"), Some(description))
+                    }
+                    _ => {
+                        if let Some(steps) = task.get("sequence").and_then(|s| s.as_array()) {
+                            let steps = poe_sequence_steps(steps);
+                            let description = format!("Runs in sequence: {}", steps.join(", "));
+                            (steps.join("\n"), Some(description))
+                        } else if let Some(reference) = task.get("ref").and_then(|r| r.as_str()) {
+                            (reference.to_string(), Some(format!("Runs: {}", reference)))
+                        } else {
+                            let content = ["cmd", "script", "shell", "expr"]
+                                .iter()
+                                .find_map(|key| task.get(key).and_then(|v| v.as_str()))
+                                .unwrap_or_default();
+                            (content.to_string(), None)
+                        }
+                    }
+                };
+                // poethepoet is usually a dev dependency, so it runs in the
+                // project's environment rather than from PATH
+                let command = match self {
+                    Self::Pip => format!("poe {}", name),
+                    _ => format!("{} run poe {}", self.tool_name(), name),
+                };
+                Script::new(
+                    name,
+                    &command,
+                    help.map(|h| h.to_string()).or(description),
+                    Some(ScriptType::from_script(name, &content)),
+                    None,
+                )
+            })
+            .collect();
+
+        Ok(scripts)
+    }
+
+    /// Lists `[tool.pdm.scripts]`. Scripts are command strings or tables with
+    /// a `cmd`, `shell`, `call` or `composite` key and an optional `help`.
+    fn parse_pdm_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let content = fs::read_to_string(path.join("pyproject.toml"))?;
+        let pyproject: Value = toml::from_str(&content)?;
+
+        let Some(pdm_scripts) = pyproject
+            .get("tool")
+            .and_then(|t| t.get("pdm"))
+            .and_then(|p| p.get("scripts"))
+            .and_then(|s| s.as_table())
+        else {
+            return Ok(Vec::new());
+        };
+
+        let scripts = pdm_scripts
+            .iter()
+            // `_` holds options shared by all scripts
+            .filter(|(name, _)| !name.starts_with('_'))
+            .map(|(name, script)| {
+                let (content, description) = match script {
+                    Value::String(cmd) => (cmd.clone(), None),
+                    _ => {
+                        if let Some(steps) = script.get("composite").and_then(|c| c.as_array()) {
+                            let steps = string_values(steps);
+                            let description = format!("Runs in sequence: {}", steps.join(", "));
+                            (steps.join("\n"), Some(description))
+                        } else {
+                            let content = ["cmd", "shell", "call"]
+                                .iter()
+                                .find_map(|key| command_value(script.get(key)))
+                                .unwrap_or_default();
+                            (content, None)
+                        }
+                    }
+                };
+                let help = script.get("help").and_then(|h| h.as_str());
+                Script::new(
+                    name,
+                    &format!("pdm run {}", name),
+                    help.map(|h| h.to_string()).or(description),
+                    Some(ScriptType::from_script(name, &content)),
+                    None,
+                )
+            })
+            .collect();
+
+        Ok(scripts)
+    }
+
+    /// Lists the scripts of each Hatch environment, from `[tool.hatch.envs]`
+    /// in pyproject.toml or `[envs]` in hatch.toml. Scripts in the default
+    /// environment keep their names; others are shown as `env:script`.
+    fn parse_hatch_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let mut env_tables = Vec::new();
+        if let Ok(content) = fs::read_to_string(path.join("pyproject.toml")) {
+            let pyproject: Value = toml::from_str(&content)?;
+            if let Some(envs) = pyproject
+                .get("tool")
+                .and_then(|t| t.get("hatch"))
+                .and_then(|h| h.get("envs"))
+                .and_then(|e| e.as_table())
+            {
+                env_tables.push(envs.clone());
+            }
+        }
+        if let Ok(content) = fs::read_to_string(path.join("hatch.toml")) {
+            let hatch: Value = toml::from_str(&content)?;
+            if let Some(envs) = hatch.get("envs").and_then(|e| e.as_table()) {
+                env_tables.push(envs.clone());
+            }
+        }
+
+        let mut scripts = Vec::new();
+        for envs in &env_tables {
+            for (env, config) in envs {
+                let Some(env_scripts) = config.get("scripts").and_then(|s| s.as_table()) else {
+                    continue;
+                };
+                for (name, commands) in env_scripts {
+                    let qualified_name = if env == "default" {
+                        name.clone()
+                    } else {
+                        format!("{}:{}", env, name)
+                    };
+                    let commands = match commands {
+                        Value::Array(commands) => string_values(commands),
+                        _ => commands.as_str().map(|c| vec![c.to_string()]).unwrap_or_default(),
+                    };
+                    scripts.push(Script::new(
+                        &qualified_name,
+                        &format!("hatch run {}", qualified_name),
+                        Some(format!("Runs in the {} environment: {}", env, commands.join("; "))),
+                        Some(ScriptType::from_script(&qualified_name, &commands.join("\n"))),
+                        None,
+                    ));
+                }
+            }
+        }
+
         Ok(scripts)
     }
+
+    /// Lists `[tool.rye.scripts]`. Scripts are command strings, argument
+    /// arrays, or tables with a `cmd`, `call` or `chain` key.
+    fn parse_rye_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let content = fs::read_to_string(path.join("pyproject.toml"))?;
+        let pyproject: Value = toml::from_str(&content)?;
+
+        let Some(rye_scripts) = pyproject
+            .get("tool")
+            .and_then(|t| t.get("rye"))
+            .and_then(|r| r.get("scripts"))
+            .and_then(|s| s.as_table())
+        else {
+            return Ok(Vec::new());
+        };
+
+        let scripts = rye_scripts
+            .iter()
+            .map(|(name, script)| {
+                let (content, description) =
+                    match script.get("chain").and_then(|c| c.as_array()) {
+                        Some(steps) => {
+                            let steps = string_values(steps);
+                            let description = format!("Runs in sequence: {}", steps.join(", "));
+                            (steps.join("\n"), Some(description))
+                        }
+                        None => {
+                            let content = command_value(Some(script))
+                                .or_else(|| {
+                                    ["cmd", "call"]
+                                        .iter()
+                                        .find_map(|key| command_value(script.get(key)))
+                                })
+                                .unwrap_or_default();
+                            (content, None)
+                        }
+                    };
+                Script::new(
+                    name,
+                    &format!("rye run {}", name),
+                    description,
+                    Some(ScriptType::from_script(name, &content)),
+                    None,
+                )
+            })
+            .collect();
+
+        Ok(scripts)
+    }
+
+    fn parse_uv_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let pyproject: Value = match fs::read_to_string(path.join("pyproject.toml")) {
+            Ok(content) => toml::from_str(&content)?,
+            Err(_) => Value::Table(Default::default()),
+        };
+        let mut scripts = Vec::new();
+
+        // Entry points from [project.scripts]
+        if let Some(entry_points) = pyproject
+            .get("project")
+            .and_then(|p| p.get("scripts"))
+            .and_then(|s| s.as_table())
+        {
+            for (name, target) in entry_points {
+                let target = target.as_str().unwrap_or_default();
+                scripts.push(Script::new(
+                    name,
+                    &format!("uv run {}", name),
+                    Some(format!("Run the {} entry point", target)),
+                    Some(ScriptType::from_script(name, target)),
+                    None,
+                ));
+            }
+        }
+
+        let dependencies = uv_dependency_names(&pyproject, path);
+        let has_dependency = |name: &str| dependencies.contains(name);
+
+        if has_dependency("ruff") {
+            scripts.push(Script::new(
+                "lint",
+                "uv run ruff check .",
+                Some("Run Ruff linter".to_string()),
+                Some(ScriptType::Lint),
+                Some('l'),
+            ));
+        } else if has_dependency("flake8") {
+            scripts.push(Script::new(
+                "lint",
+                "uv run flake8",
+                Some("Run Flake8 linter".to_string()),
+                Some(ScriptType::Lint),
+                Some('l'),
+            ));
+        } else if has_dependency("pylint") {
+            scripts.push(Script::new(
+                "lint",
+                "uv run pylint **/*.py",
+                Some("Run Pylint linter".to_string()),
+                Some(ScriptType::Lint),
+                Some('l'),
+            ));
+        }
+
+        if has_dependency("ruff") {
+            scripts.push(Script::new(
+                "format",
+                "uv run ruff format .",
+                Some("Format code with Ruff".to_string()),
+                Some(ScriptType::Format),
+                Some('f'),
+            ));
+        } else if has_dependency("black") {
+            scripts.push(Script::new(
+                "format",
+                "uv run black .",
+                Some("Format code with Black".to_string()),
+                Some(ScriptType::Format),
+                Some('f'),
+            ));
+        }
+
+        if has_dependency("pytest") {
+            scripts.push(Script::new(
+                "test",
+                "uv run pytest",
+                Some("Run tests with pytest".to_string()),
+                Some(ScriptType::Test),
+                Some('t'),
+            ));
+        }
+
+        if has_dependency("mypy") {
+            scripts.push(Script::new(
+                "typecheck",
+                "uv run mypy .",
+                Some("Type check with mypy".to_string()),
+                Some(ScriptType::TypeCheck),
+                None,
+            ));
+        } else if has_dependency("pyright") {
+            scripts.push(Script::new(
+                "typecheck",
+                "uv run pyright",
+                Some("Type check with Pyright".to_string()),
+                Some(ScriptType::TypeCheck),
+                None,
+            ));
+        }
+
+        Ok(scripts)
+    }
+}
+
+/// Collects the normalized names of a uv project's dependencies, from
+/// `[project.dependencies]`, `[project.optional-dependencies]`,
+/// `[dependency-groups]`, `[tool.uv] dev-dependencies` and `uv.lock`.
+fn uv_dependency_names(pyproject: &Value, path: &Path) -> HashSet<String> {
+    let project = pyproject.get("project");
+    let mut requirement_lists: Vec<&Value> = Vec::new();
+    requirement_lists.extend(project.and_then(|p| p.get("dependencies")));
+    if let Some(extras) = project
+        .and_then(|p| p.get("optional-dependencies"))
+        .and_then(|e| e.as_table())
+    {
+        requirement_lists.extend(extras.values());
+    }
+    if let Some(groups) = pyproject.get("dependency-groups").and_then(|g| g.as_table()) {
+        requirement_lists.extend(groups.values());
+    }
+    requirement_lists.extend(
+        pyproject
+            .get("tool")
+            .and_then(|t| t.get("uv"))
+            .and_then(|uv| uv.get("dev-dependencies")),
+    );
+
+    let mut names: HashSet<String> = requirement_lists
+        .iter()
+        .filter_map(|list| list.as_array())
+        .flatten()
+        // Group entries may also be `{ include-group = "..." }` tables
+        .filter_map(|requirement| requirement.as_str())
+        .filter_map(requirement_name)
+        .collect();
+
+    if let Ok(content) = fs::read_to_string(path.join("uv.lock")) {
+        if let Ok(lock) = toml::from_str::<Value>(&content) {
+            names.extend(
+                lock.get("package")
+                    .and_then(|p| p.as_array())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|package| package.get("name").and_then(|n| n.as_str()))
+                    .filter_map(requirement_name),
+            );
+        }
+    }
+
+    names
+}
+
+/// Returns the normalized package name of a PEP 508 requirement such as
+/// `ruff>=0.4` or `Pytest_Cov[toml]; python_version > "3.8"`.
+fn requirement_name(requirement: &str) -> Option<String> {
+    let name: String = requirement
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_lowercase().replace(['_', '.'], "-"))
+    }
 }
 
 #[cfg(test)]
@@ -283,12 +733,139 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn test_parse_poetry_scripts() {
         let poetry = PythonPackageManager::Poetry;
         let temp_dir = create_poetry_project(&std::env::temp_dir().join("poetry-project")).unwrap();
         let scripts = poetry.parse_poetry_scripts(&temp_dir.dir).unwrap();
 
         assert!(scripts.iter().any(|s| s.name == "lint" && s.script_type == ScriptType::Lint));
+        assert!(!scripts.iter().any(|s| s.name == "requests" || s.command.contains("poetry add")));
+    }
+
+    #[test]
+    fn test_parse_poetry_entry_points_and_poe_tasks() {
+        let poetry = PythonPackageManager::Poetry;
+        let temp_dir = create_poe_project(&std::env::temp_dir().join("poe-project")).unwrap();
+        let scripts = poetry.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "serve"
+            && s.command == "poetry run serve"
+            && s.description.as_deref() == Some("Run the app.server:main entry point")));
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "poetry run poe test"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Run the test suite")));
+        assert!(scripts.iter().any(|s| s.name == "check"
+            && s.description.as_deref() == Some("Runs in sequence: format, test")));
+        assert!(scripts.iter().any(|s| s.name == "ci"
+            && s.description.as_deref() == Some("Runs: check")));
+        assert!(scripts.iter().any(|s| s.name == "format" && s.script_type == ScriptType::Format));
+    }
+
+    #[test]
+    fn test_parse_uv_scripts() {
+        let temp_dir = create_uv_project(&std::env::temp_dir().join("uv-project")).unwrap();
+        let uv = PythonPackageManager::detect(&temp_dir.dir).unwrap();
+        assert!(matches!(uv, PythonPackageManager::Uv));
+        let scripts = uv.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "serve"
+            && s.command == "uv run serve"
+            && s.description.as_deref() == Some("Run the app.server:main entry point")));
+        assert!(scripts.iter().any(|s| s.name == "lint" && s.command == "uv run ruff check ."));
+        assert!(scripts.iter().any(|s| s.name == "test" && s.command == "uv run pytest"));
+        assert!(scripts.iter().any(|s| s.name == "typecheck" && s.command == "uv run mypy ."));
+    }
+
+    #[test]
+    fn test_parse_uv_poe_tasks() {
+        let temp_dir = create_uv_project(&std::env::temp_dir().join("uv-poe-project")).unwrap();
+        let pyproject = fs::read_to_string(temp_dir.dir.join("pyproject.toml")).unwrap();
+        temp_dir
+            .create_file(
+                "pyproject.toml",
+                &format!(
+                    "{}\n[tool.poe.tasks]\ntest = \"pytest -x tests\"\ndocs = \"mkdocs build\"\n",
+                    pyproject
+                ),
+            )
+            .unwrap();
+        let uv = PythonPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = uv.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "docs" && s.command == "uv run poe docs"));
+        let test: Vec<&Script> = scripts.iter().filter(|s| s.name == "test").collect();
+        assert_eq!(test.len(), 1);
+        assert_eq!(test[0].command, "uv run poe test");
+        assert!(scripts.iter().any(|s| s.name == "serve" && s.command == "uv run serve"));
+    }
+
+    #[test]
+    fn test_parse_pdm_scripts() {
+        let temp_dir = create_pdm_project(&std::env::temp_dir().join("pdm-project")).unwrap();
+        let pdm = PythonPackageManager::detect(&temp_dir.dir).unwrap();
+        assert!(matches!(pdm, PythonPackageManager::Pdm));
+        let scripts = pdm.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "pdm run test"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Run the tests")));
+        assert!(scripts.iter().any(|s| s.name == "lint" && s.script_type == ScriptType::Lint));
+        assert!(scripts.iter().any(|s| s.name == "all"
+            && s.description.as_deref() == Some("Runs in sequence: lint, test")));
+        assert!(!scripts.iter().any(|s| s.name == "_"));
+    }
+
+    #[test]
+    fn test_parse_hatch_scripts() {
+        let temp_dir = create_hatch_project(&std::env::temp_dir().join("hatch-project")).unwrap();
+        let hatch = PythonPackageManager::detect(&temp_dir.dir).unwrap();
+        assert!(matches!(hatch, PythonPackageManager::Hatch));
+        let scripts = hatch.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "hatch run test"
+            && s.script_type == ScriptType::Test));
+        assert!(scripts.iter().any(|s| s.name == "lint:check"
+            && s.command == "hatch run lint:check"
+            && s.script_type == ScriptType::Lint));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "docs:build" && s.script_type == ScriptType::Build));
+    }
+
+    #[test]
+    fn test_parse_rye_scripts() {
+        let temp_dir = create_rye_project(&std::env::temp_dir().join("rye-project")).unwrap();
+        let rye = PythonPackageManager::detect(&temp_dir.dir).unwrap();
+        assert!(matches!(rye, PythonPackageManager::Rye));
+        let scripts = rye.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "rye run test"
+            && s.script_type == ScriptType::Test));
+        assert!(scripts.iter().any(|s| s.name == "fmt" && s.script_type == ScriptType::Format));
+        assert!(scripts.iter().any(|s| s.name == "check"
+            && s.description.as_deref() == Some("Runs in sequence: fmt, test")));
+    }
+
+    #[test]
+    fn test_lock_file_conflict() {
+        let temp_dir =
+            create_lock_conflict_project(&std::env::temp_dir().join("py-lock-conflict")).unwrap();
+        let dir = temp_dir.dir.join("python");
+        let uv = PythonPackageManager::detect(&dir).unwrap();
+
+        let warnings = uv.warnings(&dir);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "Conflicting lock files poetry.lock, uv.lock; using uv"
+        );
+        assert!(warnings[0].explanation.contains("`rm poetry.lock`"));
+        assert!(warnings[0]
+            .explanation
+            .contains("in the order poetry, pdm, uv, rye"));
     }
 }
@@ -0,0 +1,425 @@
+use anyhow::Result;
+
+use walkdir::WalkDir;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use super::PackageManager;
+use crate::types::{Script, ScriptType};
+
+const RAKEFILE_NAMES: &[&str] = &["Rakefile", "rakefile", "Rakefile.rb", "rakefile.rb"];
+
+/// Rake loads `.rake` files from `rakelib`, and Rails apps from `lib/tasks`.
+const RAKE_TASK_DIRS: &[&str] = &["rakelib", "lib/tasks"];
+
+/// Task classes that define a task whose name defaults to the first element,
+/// with the description they give it.
+const TASK_CLASSES: &[(&str, &str, &str)] = &[
+    ("Rake::TestTask", "test", "Run tests"),
+    ("RSpec::Core::RakeTask", "spec", "Run RSpec code examples"),
+    ("RuboCop::RakeTask", "rubocop", "Run RuboCop"),
+];
+
+pub struct RubyPackageManager {
+    /// Whether the project's gems are locked by Bundler, in which case rake
+    /// runs through `bundle exec`.
+    bundler: bool,
+}
+
+#[derive(Debug)]
+struct RakeTask {
+    name: String,
+    description: Option<String>,
+    arguments: Vec<String>,
+    prerequisites: Vec<String>,
+}
+
+impl PackageManager for RubyPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("Gemfile").exists() || find_rakefile(dir).is_some() {
+            Some(RubyPackageManager {
+                bundler: dir.join("Gemfile.lock").exists(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "rake"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        if self.bundler {
+            let mut cmd = Command::new("bundle");
+            cmd.arg("exec").arg("rake").arg(script);
+            cmd
+        } else {
+            let mut cmd = Command::new("rake");
+            cmd.arg(script);
+            cmd
+        }
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let Some(rakefile) = find_rakefile(path) else {
+            return Ok(Vec::new());
+        };
+        let mut tasks = parse_rake_tasks(&fs::read_to_string(rakefile)?);
+        // An unreadable task file only loses its own tasks
+        for content in find_rake_task_files(path)
+            .iter()
+            .filter_map(|file| fs::read_to_string(file).ok())
+        {
+            tasks.extend(parse_rake_tasks(&content));
+        }
+
+        let runner = self.runner();
+        let mut scripts: Vec<Script> = Vec::new();
+        for task in tasks {
+            // Rake merges repeated definitions of a task
+            if let Some(script) = scripts.iter_mut().find(|s| s.name == task.name) {
+                script.depends_on.extend(task.prerequisites);
+                if script.description.is_none() {
+                    script.description = task.description;
+                }
+                continue;
+            }
+            let description = match (task.description, task.arguments.is_empty()) {
+                (Some(description), true) => Some(description),
+                (Some(description), false) => Some(format!(
+                    "{} (arguments: {})",
+                    description,
+                    task.arguments.join(", ")
+                )),
+                (None, false) => Some(format!("Arguments: {}", task.arguments.join(", "))),
+                (None, true) => None,
+            };
+            let script_type =
+                ScriptType::from_script(&task.name, description.as_deref().unwrap_or_default());
+            scripts.push(
+                Script::new(
+                    &task.name,
+                    &format!("{} {}", runner, task.name),
+                    description,
+                    Some(script_type),
+                    None,
+                )
+                .with_depends_on(task.prerequisites),
+            );
+        }
+        Ok(scripts)
+    }
+
+    fn tool_description(&self, _path: &Path) -> Option<String> {
+        Some(self.runner().to_string())
+    }
+}
+
+impl RubyPackageManager {
+    fn runner(&self) -> &'static str {
+        if self.bundler {
+            "bundle exec rake"
+        } else {
+            "rake"
+        }
+    }
+}
+
+fn find_rakefile(dir: &Path) -> Option<PathBuf> {
+    RAKEFILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// Returns the `.rake` files that rake or Rails load alongside the Rakefile.
+fn find_rake_task_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = RAKE_TASK_DIRS
+        .iter()
+        .flat_map(|tasks_dir| WalkDir::new(dir.join(tasks_dir)).into_iter())
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.into_path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rake"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Extracts the tasks defined in a Rakefile or `.rake` file, qualifying
+/// tasks inside `namespace` blocks as `ns:task`.
+///
+/// This reads the Rake DSL line by line rather than evaluating Ruby, so
+/// tasks defined in loops or by helper methods are missed.
+fn parse_rake_tasks(content: &str) -> Vec<RakeTask> {
+    let mut tasks = Vec::new();
+    let mut description: Option<String> = None;
+    // The open namespaces, with the block depth at which each was opened
+    let mut namespaces: Vec<(String, usize)> = Vec::new();
+    let mut depth = 0;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(rest) = keyword_arguments(line, "desc") {
+            description = string_literal(rest).map(|(s, _)| s);
+        } else if let Some(rest) = keyword_arguments(line, "namespace") {
+            if let Some((name, _)) = task_name(rest) {
+                namespaces.push((name, depth));
+            }
+        } else if let Some(rest) =
+            keyword_arguments(line, "task").or_else(|| keyword_arguments(line, "multitask"))
+        {
+            if let Some(mut task) = parse_task_definition(rest) {
+                task.name = qualified_name(&namespaces, &task.name);
+                task.description = description.take();
+                tasks.push(task);
+            }
+        } else if let Some((class, name, default_description)) = TASK_CLASSES
+            .iter()
+            .find(|(class, _, _)| line.starts_with(&format!("{}.new", class)))
+        {
+            let arguments = line[class.len() + ".new".len()..].trim_start_matches('(');
+            let name = task_name(arguments)
+                .map(|(name, _)| name)
+                .unwrap_or_else(|| name.to_string());
+            tasks.push(RakeTask {
+                name: qualified_name(&namespaces, &name),
+                description: description
+                    .take()
+                    .or_else(|| Some(default_description.to_string())),
+                arguments: Vec::new(),
+                prerequisites: Vec::new(),
+            });
+        }
+
+        if is_block_end(line) {
+            depth = depth.saturating_sub(1);
+            if namespaces.last().is_some_and(|(_, d)| *d == depth) {
+                namespaces.pop();
+            }
+        } else if opens_block(line) {
+            depth += 1;
+        }
+    }
+
+    tasks
+}
+
+fn qualified_name(namespaces: &[(String, usize)], name: &str) -> String {
+    namespaces
+        .iter()
+        .map(|(namespace, _)| namespace.as_str())
+        .chain([name])
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Returns the text after a method call such as `task` at the start of a
+/// line, with or without parentheses.
+fn keyword_arguments<'a>(line: &'a str, keyword: &str) -> Option<&'a str> {
+    let rest = line.strip_prefix(keyword)?;
+    if rest.starts_with(' ') || rest.starts_with('(') {
+        Some(rest.trim_start_matches('(').trim_start())
+    } else {
+        None
+    }
+}
+
+/// Parses the arguments of `task`, in any of its forms:
+///
+/// ```ruby
+/// task :name
+/// task name: [:prerequisite]
+/// task :name, [:argument] => :prerequisite
+/// task "name" => %w[prerequisite]
+/// ```
+fn parse_task_definition(arguments: &str) -> Option<RakeTask> {
+    let (name, rest) = task_name(arguments)?;
+    let rest = rest.trim_start();
+
+    let (task_arguments, rest) = match rest.strip_prefix(',') {
+        Some(rest) => {
+            let rest = rest.trim_start();
+            let end = rest.find(']').map(|i| i + 1).unwrap_or(rest.len());
+            (symbols(&rest[..end]), &rest[end..])
+        }
+        None => (Vec::new(), rest),
+    };
+
+    // `name: deps` leaves the remainder starting after the colon
+    let rest = rest.trim_start();
+    let prerequisites = match rest.strip_prefix("=>") {
+        Some(rest) => symbols(strip_block(rest)),
+        None if arguments
+            .trim_start()
+            .starts_with(|c: char| c.is_alphabetic()) =>
+        {
+            symbols(strip_block(rest))
+        }
+        None => Vec::new(),
+    };
+
+    Some(RakeTask {
+        name,
+        description: None,
+        arguments: task_arguments,
+        prerequisites,
+    })
+}
+
+/// Reads a task or namespace name given as a symbol (`:name`), a string
+/// (`"name"`), or a hash key (`name:`), returning it and the text after it.
+fn task_name(text: &str) -> Option<(String, &str)> {
+    let text = text.trim_start();
+    if let Some(symbol) = text.strip_prefix(':') {
+        let (name, rest) = split_identifier(symbol);
+        return (!name.is_empty()).then(|| (name.to_string(), rest));
+    }
+    if let Some((name, rest)) = string_literal(text) {
+        return Some((name, rest));
+    }
+    let (name, rest) = split_identifier(text);
+    let rest = rest.strip_prefix(':')?;
+    (!name.is_empty()).then(|| (name.to_string(), rest))
+}
+
+fn split_identifier(text: &str) -> (&str, &str) {
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '?' || c == '!'))
+        .unwrap_or(text.len());
+    text.split_at(end)
+}
+
+/// Reads a single- or double-quoted string at the start of `text`.
+fn string_literal(text: &str) -> Option<(String, &str)> {
+    let text = text.trim_start();
+    let quote = text.chars().next().filter(|&c| c == '"' || c == '\'')?;
+    let end = text[1..].find(quote)? + 1;
+    Some((text[1..end].to_string(), &text[end + 1..]))
+}
+
+/// Returns the names in a list of symbols, strings or `%w[]` words, such as
+/// the prerequisites of a task.
+fn symbols(text: &str) -> Vec<String> {
+    let text = text.trim();
+    if let Some(words) = text
+        .strip_prefix("%w[")
+        .or_else(|| text.strip_prefix("%i["))
+    {
+        return words
+            .trim_end_matches(']')
+            .split_whitespace()
+            .map(|word| word.to_string())
+            .collect();
+    }
+    text.trim_start_matches('[')
+        .trim_end_matches(')')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|item| {
+            item.trim()
+                .trim_start_matches(':')
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string()
+        })
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Removes a trailing `do ... |args|` or `{ ... }` block from a task line.
+fn strip_block(text: &str) -> &str {
+    let end = [text.find(" do"), text.find('{')]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(text.len());
+    &text[..end]
+}
+
+fn opens_block(line: &str) -> bool {
+    let line = line.split(" # ").next().unwrap_or(line).trim_end();
+    let without_parameters = match line.strip_suffix('|') {
+        Some(rest) => rest
+            .rfind('|')
+            .map(|i| rest[..i].trim_end())
+            .unwrap_or(rest),
+        None => line,
+    };
+    without_parameters == "do"
+        || without_parameters.ends_with(" do")
+        || [
+            "def ", "class ", "module ", "if ", "unless ", "case ", "while ", "until ",
+        ]
+        .iter()
+        .any(|keyword| line.starts_with(keyword))
+        || line == "begin"
+}
+
+fn is_block_end(line: &str) -> bool {
+    line == "end" || line.starts_with("end ") || line.starts_with("end.") || line == "end)"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir = create_rake_project(&std::env::temp_dir().join("rake-project")).unwrap();
+        // A task file that isn't UTF-8 is skipped
+        fs::write(temp_dir.dir.join("lib/tasks/legacy.rake"), b"task :old\xff\n").unwrap();
+        let rake = RubyPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = rake.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            [
+                "test",
+                "lint",
+                "default",
+                "db:seed",
+                "db:cache:clear",
+                "assets",
+                "cleanup:sessions"
+            ]
+        );
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "bundle exec rake test"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Run tests")));
+        assert!(scripts.iter().any(|s| s.name == "lint"
+            && s.script_type == ScriptType::Lint
+            && s.description.as_deref() == Some("Run the linter")));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "default" && s.depends_on == ["lint", "test"]));
+        assert!(scripts.iter().any(|s| s.name == "db:seed"
+            && s.depends_on == ["environment"]
+            && s.description.as_deref() == Some("Load the seed data")));
+        assert!(scripts.iter().any(|s| s.name == "db:cache:clear"
+            && s.depends_on == ["environment"]
+            && s.description.as_deref() == Some("Clear the query cache (arguments: scope)")));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "assets" && s.description.is_none()));
+        assert!(scripts.iter().any(|s| s.name == "cleanup:sessions"
+            && s.description.as_deref() == Some("Remove expired sessions")));
+
+        let command = rake.script_command(&scripts[0]);
+        assert_eq!(command.get_program(), "bundle");
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            ["exec", "rake", "test"]
+        );
+    }
+}
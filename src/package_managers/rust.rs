@@ -1,13 +1,95 @@
 use anyhow::Result;
 use toml::Value;
 
-use std::{fs, path::Path, process::Command};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
-use super::PackageManager;
+use super::cargo_make::find_cargo_make_tasks;
+use super::diagnostics::{shadowed_member_script, unparsable_config};
+use super::workspace::expand_workspace_pattern;
+use super::{shell_command, shell_quote, PackageManager, Warning, WorkspaceMember};
 use crate::types::{Script, ScriptType};
 
+/// Cargo's built-in subcommands and their one-letter aliases, which
+/// `[alias]` entries can't redefine.
+const CARGO_BUILTIN_COMMANDS: &[&str] = &[
+    "add",
+    "b",
+    "bench",
+    "build",
+    "c",
+    "check",
+    "clean",
+    "config",
+    "d",
+    "doc",
+    "fetch",
+    "fix",
+    "generate-lockfile",
+    "help",
+    "info",
+    "init",
+    "install",
+    "locate-project",
+    "login",
+    "logout",
+    "metadata",
+    "new",
+    "owner",
+    "package",
+    "pkgid",
+    "publish",
+    "r",
+    "read-manifest",
+    "remove",
+    "report",
+    "rm",
+    "run",
+    "rustc",
+    "rustdoc",
+    "search",
+    "t",
+    "test",
+    "tree",
+    "uninstall",
+    "update",
+    "vendor",
+    "verify-project",
+    "version",
+    "yank",
+];
+
 pub struct RustPackageManager;
 
+/// A crate listed in the `members` of a `[workspace]` table.
+struct CargoMember {
+    name: String,
+    dir: PathBuf,
+    manifest: Value,
+}
+
+impl CargoMember {
+    /// Returns the names of the packages the crate depends on to build.
+    /// Dev-dependencies are left out, since they may form cycles.
+    fn dependencies(&self) -> Vec<String> {
+        ["dependencies", "build-dependencies"]
+            .iter()
+            .filter_map(|table| self.manifest.get(table).and_then(|t| t.as_table()))
+            .flatten()
+            .map(|(key, spec)| {
+                // Renamed dependencies name the package with `package = "..."`
+                spec.get("package")
+                    .and_then(|p| p.as_str())
+                    .unwrap_or(key)
+                    .to_string()
+            })
+            .collect()
+    }
+}
+
 impl PackageManager for RustPackageManager {
     fn detect(dir: &Path) -> Option<Self> {
         if dir.join("Cargo.toml").exists() {
@@ -17,6 +99,10 @@ impl PackageManager for RustPackageManager {
         }
     }
 
+    fn tool_name(&self) -> &'static str {
+        "cargo"
+    }
+
     fn run_command(&self, script: &str) -> Command {
         let mut cmd = Command::new("cargo");
         cmd.arg(script);
@@ -114,30 +200,454 @@ impl PackageManager for RustPackageManager {
             }
         }
 
-        // Parse binary targets
-        if let Some(bin) = cargo_toml.get("bin") {
-            if let Some(binaries) = bin.as_array() {
-                for binary in binaries {
-                    if let Some(name) = binary.get("name").and_then(|n| n.as_str()) {
-                        scripts.push(Script::new(
-                            &format!("run:{}", name),
-                            &format!("cargo run --bin {}", name),
-                            Some(format!("Run the {} binary", name)),
-                            None,
-                            None,
-                        ));
+        // Add binary, example and benchmark targets. A lone default binary is
+        // what `run` already runs.
+        if let Some(package_name) = cargo_toml
+            .get("package")
+            .and_then(|p| p.get("name"))
+            .and_then(|n| n.as_str())
+        {
+            let targets = cargo_targets(path, &cargo_toml, package_name);
+            let binaries: Vec<&CargoTarget> =
+                targets.iter().filter(|t| t.kind == TargetKind::Bin).collect();
+            let lone_default_binary = binaries.len() == 1
+                && binaries[0].name == package_name
+                && binaries[0].required_features.is_empty();
+            for target in &targets {
+                if !(lone_default_binary && target.kind == TargetKind::Bin) {
+                    scripts.push(target.script(None));
+                }
+            }
+        }
+
+        // Add cargo-make tasks, which take the place of the default command
+        // of the same name. A malformed Makefile.toml is reported by
+        // `warnings` rather than hiding the other scripts.
+        for task in find_cargo_make_tasks(path).unwrap_or_default() {
+            match scripts.iter_mut().find(|s| s.name == task.name) {
+                Some(script) => {
+                    *script = Script {
+                        shortcut: script.shortcut,
+                        ..task
+                    }
+                }
+                None => scripts.push(task),
+            }
+        }
+
+        // Add scripts scoped to each member of a workspace. One that a root
+        // script shadows is reported by `warnings`.
+        for member in find_workspace_members(path, &cargo_toml) {
+            for script in member_scripts(path, &member) {
+                if !scripts.iter().any(|s| s.name == script.name) {
+                    scripts.push(script);
+                }
+            }
+        }
+
+        // Parse aliases from .cargo/config.toml in this and parent directories
+        for (name, expansion) in find_cargo_aliases(path) {
+            if CARGO_BUILTIN_COMMANDS.contains(&name.as_str()) {
+                // Cargo doesn't let aliases shadow built-in commands
+                continue;
+            }
+            let alias = Script::new(
+                &name,
+                &format!("cargo {}", name),
+                Some(format!("Alias for `cargo {}`", expansion)),
+                Some(ScriptType::from_script(&name, &expansion)),
+                None,
+            );
+            // An alias named after a default script, such as `lint`, replaces it
+            match scripts.iter_mut().find(|s| s.name == name) {
+                Some(script) => {
+                    *script = Script {
+                        shortcut: script.shortcut,
+                        ..alias
                     }
                 }
+                None => scripts.push(alias),
             }
         }
 
         Ok(scripts)
     }
+
+    // Workspace member scripts and custom scripts aren't cargo subcommands,
+    // so each script's own command is what runs it.
+    fn script_command(&self, script: &Script) -> Command {
+        shell_command(&script.command)
+    }
+
+    fn warnings(&self, path: &Path) -> Vec<Warning> {
+        let mut warnings: Vec<Warning> = find_cargo_make_tasks(path)
+            .err()
+            .map(|error| unparsable_config("Makefile.toml", &error))
+            .into_iter()
+            .collect();
+
+        let (Some(cargo_toml), Ok(scripts)) = (
+            fs::read_to_string(path.join("Cargo.toml"))
+                .ok()
+                .and_then(|content| toml::from_str::<Value>(&content).ok()),
+            self.find_scripts(path),
+        ) else {
+            return warnings;
+        };
+        for member in find_workspace_members(path, &cargo_toml) {
+            for script in member_scripts(path, &member) {
+                if !scripts
+                    .iter()
+                    .any(|s| s.name == script.name && s.command == script.command)
+                {
+                    warnings.push(shadowed_member_script(&script.name, &member.name));
+                }
+            }
+        }
+        warnings
+    }
+
+    fn workspace_members(&self, path: &Path) -> Vec<WorkspaceMember> {
+        let Some(cargo_toml) = fs::read_to_string(path.join("Cargo.toml"))
+            .ok()
+            .and_then(|content| toml::from_str::<Value>(&content).ok())
+        else {
+            return Vec::new();
+        };
+        find_workspace_members(path, &cargo_toml)
+            .into_iter()
+            .map(|member| WorkspaceMember {
+                dependencies: member.dependencies(),
+                name: member.name,
+                path: member.dir,
+                package_manager: Box::new(RustPackageManager),
+            })
+            .collect()
+    }
+}
+
+/// Returns the member crates of the workspace declared in `cargo_toml`,
+/// expanding glob patterns in `members` and leaving out `exclude`d paths and
+/// the root package itself.
+fn find_workspace_members(root: &Path, cargo_toml: &Value) -> Vec<CargoMember> {
+    let Some(workspace) = cargo_toml.get("workspace") else {
+        return Vec::new();
+    };
+    let paths = |key: &str| -> Vec<&str> {
+        workspace
+            .get(key)
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str())
+            .collect()
+    };
+    let excluded: Vec<PathBuf> = paths("exclude").iter().map(|p| root.join(p)).collect();
+
+    let mut members: Vec<CargoMember> = Vec::new();
+    for pattern in paths("members") {
+        for dir in expand_workspace_pattern(root, pattern) {
+            if dir == root || excluded.contains(&dir) || members.iter().any(|m| m.dir == dir) {
+                continue;
+            }
+            let Some(manifest) = fs::read_to_string(dir.join("Cargo.toml"))
+                .ok()
+                .and_then(|content| toml::from_str::<Value>(&content).ok())
+            else {
+                continue;
+            };
+            let Some(name) = manifest
+                .get("package")
+                .and_then(|p| p.get("name"))
+                .and_then(|n| n.as_str())
+                .map(|n| n.to_string())
+            else {
+                continue;
+            };
+            members.push(CargoMember {
+                name,
+                dir,
+                manifest,
+            });
+        }
+    }
+    members
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TargetKind {
+    Bin,
+    Example,
+    Bench,
+}
+
+impl TargetKind {
+    /// Returns the manifest table, the auto-discovery directory and the
+    /// `[package]` flag that disables auto-discovery for this kind of target.
+    fn layout(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::Bin => ("bin", "src/bin", "autobins"),
+            Self::Example => ("example", "examples", "autoexamples"),
+            Self::Bench => ("bench", "benches", "autobenches"),
+        }
+    }
+}
+
+/// A binary, example or benchmark target of a crate.
+struct CargoTarget {
+    kind: TargetKind,
+    name: String,
+    path: Option<String>,
+    required_features: Vec<String>,
+}
+
+impl CargoTarget {
+    /// Returns the script that runs the target, selecting `package` with `-p`
+    /// when the target belongs to a workspace member.
+    fn script(&self, package: Option<&str>) -> Script {
+        let name = &self.name;
+        let (script_name, mut command, description, script_type) = match self.kind {
+            TargetKind::Bin => (
+                format!("run:{}", name),
+                format!("cargo run --bin {}", name),
+                format!("Run the {} binary", name),
+                ScriptType::Serve,
+            ),
+            TargetKind::Example => (
+                format!("example:{}", name),
+                format!("cargo run --example {}", name),
+                format!("Run the {} example", name),
+                ScriptType::Serve,
+            ),
+            TargetKind::Bench => (
+                format!("bench:{}", name),
+                format!("cargo bench --bench {}", name),
+                format!("Run the {} benchmark", name),
+                ScriptType::Test,
+            ),
+        };
+        let description = match package {
+            Some(package) => {
+                command.push_str(&format!(" -p {}", package));
+                format!("{} of the {} crate", description, package)
+            }
+            None => description,
+        };
+        if !self.required_features.is_empty() {
+            command.push_str(&format!(" --features {}", self.required_features.join(",")));
+        }
+        Script::new(
+            &script_name,
+            &command,
+            Some(description),
+            Some(script_type),
+            None,
+        )
+    }
+}
+
+/// Returns a crate's binaries, examples and benchmarks: those declared in
+/// `[[bin]]`, `[[example]]` and `[[bench]]` tables, followed by those Cargo
+/// discovers from `src/main.rs`, `src/bin/`, `examples/` and `benches/`.
+fn cargo_targets(dir: &Path, manifest: &Value, package_name: &str) -> Vec<CargoTarget> {
+    let mut targets: Vec<CargoTarget> = Vec::new();
+
+    for kind in [TargetKind::Bin, TargetKind::Example, TargetKind::Bench] {
+        let (table, source_dir, auto_flag) = kind.layout();
+        let start = targets.len();
+
+        for target in manifest
+            .get(table)
+            .and_then(|t| t.as_array())
+            .into_iter()
+            .flatten()
+        {
+            let Some(name) = target.get("name").and_then(|n| n.as_str()) else {
+                continue;
+            };
+            let required_features = target
+                .get("required-features")
+                .and_then(|f| f.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|f| f.as_str())
+                .map(|f| f.to_string())
+                .collect();
+            targets.push(CargoTarget {
+                kind,
+                name: name.to_string(),
+                path: target
+                    .get("path")
+                    .and_then(|p| p.as_str())
+                    .map(|p| p.trim_start_matches("./").to_string()),
+                required_features,
+            });
+        }
+
+        let auto_discover = manifest
+            .get("package")
+            .and_then(|p| p.get(auto_flag))
+            .and_then(|a| a.as_bool())
+            .unwrap_or(true);
+        if !auto_discover {
+            continue;
+        }
+        let mut discovered = discover_targets(dir, source_dir);
+        if kind == TargetKind::Bin && dir.join("src/main.rs").exists() {
+            discovered.insert(0, (package_name.to_string(), "src/main.rs".to_string()));
+        }
+        for (name, path) in discovered {
+            // A table that names the target or points at its file overrides it
+            let declared = targets[start..]
+                .iter()
+                .any(|t| t.name == name || t.path.as_deref() == Some(path.as_str()));
+            if !declared {
+                targets.push(CargoTarget {
+                    kind,
+                    name,
+                    path: Some(path),
+                    required_features: Vec::new(),
+                });
+            }
+        }
+    }
+
+    targets
+}
+
+/// Returns the names and crate-relative paths of the targets in an
+/// auto-discovery directory: each `<name>.rs` file, and each `<name>/main.rs`.
+fn discover_targets(dir: &Path, source_dir: &str) -> Vec<(String, String)> {
+    let mut targets: Vec<(String, String)> = fs::read_dir(dir.join(source_dir))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            if path.is_dir() {
+                path.join("main.rs").exists().then(|| {
+                    let relative_path = format!("{}/{}/main.rs", source_dir, file_name);
+                    (file_name, relative_path)
+                })
+            } else {
+                let name = file_name.strip_suffix(".rs")?.to_string();
+                Some((name, format!("{}/{}", source_dir, file_name)))
+            }
+        })
+        .collect();
+    targets.sort();
+    targets
+}
+
+/// Returns the scripts that build, test and run a single workspace member,
+/// and its own `[package.metadata.scripts]`, named `<crate>:<script>`: for
+/// instance `<crate>:build`, `<crate>:run:<bin>` (`<crate>:run` for the
+/// binary named after the crate) and `<crate>:example:<name>`. A custom
+/// script named after a generated one, such as `test`, replaces it.
+fn member_scripts(root: &Path, member: &CargoMember) -> Vec<Script> {
+    let name = &member.name;
+    let mut scripts = vec![
+        Script::new(
+            &format!("{}:build", name),
+            &format!("cargo build -p {}", name),
+            Some(format!("Compile the {} crate", name)),
+            Some(ScriptType::Build),
+            None,
+        ),
+        Script::new(
+            &format!("{}:test", name),
+            &format!("cargo test -p {}", name),
+            Some(format!("Run the tests of the {} crate", name)),
+            Some(ScriptType::Test),
+            None,
+        ),
+    ];
+
+    for target in cargo_targets(&member.dir, &member.manifest, name) {
+        let script = target.script(Some(name));
+        let script_name = if target.kind == TargetKind::Bin && target.name == *name {
+            format!("{}:run", name)
+        } else {
+            format!("{}:{}", name, script.name)
+        };
+        scripts.push(Script {
+            name: script_name,
+            ..script
+        });
+    }
+
+    let custom_scripts = member
+        .manifest
+        .get("package")
+        .and_then(|p| p.get("metadata"))
+        .and_then(|m| m.get("scripts"))
+        .and_then(|s| s.as_table());
+    let relative_dir = member.dir.strip_prefix(root).unwrap_or(&member.dir);
+    for (script_name, value) in custom_scripts.into_iter().flatten() {
+        if let Some(command) = value.as_str() {
+            let script = Script::new(
+                &format!("{}:{}", name, script_name),
+                &format!(
+                    "cd {} && {}",
+                    shell_quote(&relative_dir.to_string_lossy()),
+                    command
+                ),
+                None,
+                Some(ScriptType::from_script(script_name, command)),
+                None,
+            );
+            match scripts.iter_mut().find(|s| s.name == script.name) {
+                Some(existing) => *existing = script,
+                None => scripts.push(script),
+            }
+        }
+    }
+
+    scripts
+}
+
+/// Collects the `[alias]` entries of the Cargo configuration files that apply
+/// to `path`, as `(name, expansion)` pairs. As in Cargo, configuration in a
+/// directory takes precedence over its parents'.
+fn find_cargo_aliases(path: &Path) -> Vec<(String, String)> {
+    let mut aliases: Vec<(String, String)> = Vec::new();
+
+    for dir in path.ancestors() {
+        for config_name in ["config.toml", "config"] {
+            let Ok(content) = fs::read_to_string(dir.join(".cargo").join(config_name)) else {
+                continue;
+            };
+            let Some(alias_table) = toml::from_str::<Value>(&content)
+                .ok()
+                .and_then(|config| config.get("alias").and_then(|a| a.as_table()).cloned())
+            else {
+                continue;
+            };
+            for (name, value) in alias_table {
+                let expansion = match value {
+                    Value::String(s) => s,
+                    Value::Array(args) => args
+                        .iter()
+                        .filter_map(|arg| arg.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    _ => continue,
+                };
+                if !aliases.iter().any(|(existing, _)| *existing == name) {
+                    aliases.push((name, expansion));
+                }
+            }
+        }
+    }
+
+    aliases
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests::project_dir_mocks::*;
+    use crate::types::Phase;
     use super::*;
 
     #[test]
@@ -151,4 +661,163 @@ mod tests {
         assert!(scripts.iter().any(|s| s.name == "lint" && s.script_type == ScriptType::Lint));
         assert!(scripts.iter().any(|s| s.name == "fix" && s.script_type == ScriptType::Format));
     }
+
+    #[test]
+    fn test_find_cargo_aliases() {
+        let rust = RustPackageManager;
+        let temp_dir =
+            create_cargo_alias_project(&std::env::temp_dir().join("cargo-alias-workspace"))
+                .unwrap();
+        let crate_dir = temp_dir.dir.join("member");
+        let scripts = rust.find_scripts(&crate_dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "xtask"
+            && s.command == "cargo xtask"
+            && s.description.as_deref() == Some("Alias for `cargo run --package xtask --`")));
+        assert!(scripts.iter().any(|s| s.name == "ci"
+            && s.description.as_deref() == Some("Alias for `cargo test --workspace --locked`")
+            && s.script_type == ScriptType::Test));
+        // The member's own configuration overrides the parent's
+        assert!(scripts.iter().any(|s| s.name == "lint-all"
+            && s.description.as_deref() == Some("Alias for `cargo clippy --all-targets`")
+            && s.script_type == ScriptType::Lint));
+        // Aliases can't shadow built-in commands
+        assert!(scripts.iter().any(|s| s.name == "build" && s.command == "cargo build"));
+        assert_eq!(scripts.iter().filter(|s| s.name == "build").count(), 1);
+        // but do replace psr's default scripts
+        assert!(scripts.iter().any(|s| s.name == "lint"
+            && s.command == "cargo lint"
+            && s.description.as_deref() == Some("Alias for `cargo clippy -- -D warnings`")
+            && s.shortcut == Some('l')));
+        assert_eq!(scripts.iter().filter(|s| s.name == "lint").count(), 1);
+    }
+
+    #[test]
+    fn test_find_workspace_member_scripts() {
+        let rust = RustPackageManager;
+        let temp_dir =
+            create_cargo_workspace_project(&std::env::temp_dir().join("cargo-workspace")).unwrap();
+        let scripts = rust.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "app-core:test"
+            && s.command == "cargo test -p app-core"
+            && s.script_type == ScriptType::Test));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "app-cli:run" && s.command == "cargo run --bin app-cli -p app-cli"));
+        assert!(scripts.iter().any(|s| s.name == "app-cli:run:app-admin"
+            && s.command == "cargo run --bin app-admin -p app-cli"));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "xtask:run" && s.command == "cargo run --bin xtask -p xtask"));
+        assert!(scripts.iter().any(|s| s.name == "app-cli:dev"
+            && s.command == "cd crates/cli && cargo watch -x run"));
+        // Member directories are quoted for the shell
+        assert!(scripts.iter().any(|s| s.name == "site-docs:serve"
+            && s.command == "cd 'tools/site docs' && mdbook serve"));
+        // Libraries have no binaries, and excluded members are left out
+        assert!(!scripts.iter().any(|s| s.name.starts_with("app-core:run")));
+        assert!(!scripts.iter().any(|s| s.name.contains("app-experimental")));
+        assert!(rust.warnings(&temp_dir.dir).is_empty());
+    }
+
+    #[test]
+    fn test_shadowed_workspace_member_scripts() {
+        let rust = RustPackageManager;
+        let temp_dir =
+            create_cargo_workspace_project(&std::env::temp_dir().join("cargo-workspace-shadowed"))
+                .unwrap();
+        temp_dir
+            .create_file(
+                "crates/core/Cargo.toml",
+                "[package]\nname = \"app-core\"\n\n[package.metadata.scripts]\ntest = \"cargo nextest run\"\n",
+            )
+            .unwrap();
+        temp_dir
+            .create_file("Makefile.toml", "[tasks.\"app-cli:dev\"]\ncommand = \"bacon\"\n")
+            .unwrap();
+        let scripts = rust.find_scripts(&temp_dir.dir).unwrap();
+
+        // A member's custom script replaces the generated one of the same name
+        let test: Vec<&Script> = scripts.iter().filter(|s| s.name == "app-core:test").collect();
+        assert_eq!(test.len(), 1);
+        assert_eq!(test[0].command, "cd crates/core && cargo nextest run");
+        // A root script of the same name hides a member's, with a warning
+        assert_eq!(scripts.iter().filter(|s| s.name == "app-cli:dev").count(), 1);
+        let warnings = rust.warnings(&temp_dir.dir);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("app-cli:dev"));
+    }
+
+    #[test]
+    fn test_find_target_scripts() {
+        let rust = RustPackageManager;
+        let temp_dir =
+            create_cargo_targets_project(&std::env::temp_dir().join("cargo-targets")).unwrap();
+        let scripts = rust.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts
+            .iter()
+            .map(|s| s.name.as_str())
+            .filter(|name| name.contains(':'))
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "run:server",
+                "run:migrate",
+                "run:seed",
+                "example:gui",
+                "example:hello",
+                "bench:throughput",
+                "bench:parse"
+            ]
+        );
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "run:seed" && s.command == "cargo run --bin seed"));
+        assert!(scripts.iter().any(|s| s.name == "example:gui"
+            && s.command == "cargo run --example gui --features gui,images"));
+        assert!(scripts.iter().any(|s| s.name == "bench:parse"
+            && s.command == "cargo bench --bench parse"
+            && s.script_type == ScriptType::Test));
+    }
+
+    #[test]
+    fn test_find_cargo_make_tasks() {
+        let rust = RustPackageManager;
+        let temp_dir =
+            create_cargo_make_project(&std::env::temp_dir().join("cargo-make-project")).unwrap();
+        let scripts = rust.find_scripts(&temp_dir.dir).unwrap();
+
+        // A task named after a default command takes its place
+        assert_eq!(scripts.iter().filter(|s| s.name == "test").count(), 1);
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.command == "cargo make test"
+            && s.shortcut == Some('t')
+            && s.description.as_deref() == Some("Run the tests with coverage")));
+        assert!(scripts.iter().any(|s| s.name == "ci-flow"
+            && s.command == "cargo make ci-flow"
+            && s.description.as_deref() == Some("Run the CI checks")));
+        assert!(scripts.iter().any(|s| s.name == "format-check"
+            && s.script_type == ScriptType::Format));
+        assert!(scripts.iter().any(|s| s.name == "docs"
+            && s.script_type == ScriptType::Build
+            && s.phase == Phase::Build));
+        assert!(!scripts.iter().any(|s| s.name == "setup-hooks"));
+    }
+
+    #[test]
+    fn test_malformed_cargo_make_tasks() {
+        let rust = RustPackageManager;
+        let temp_dir =
+            create_cargo_project(&std::env::temp_dir().join("malformed-cargo-make")).unwrap();
+        temp_dir.create_file("Makefile.toml", "[tasks.test\n").unwrap();
+        let scripts = rust.find_scripts(&temp_dir.dir).unwrap();
+
+        assert!(scripts.iter().any(|s| s.name == "test" && s.command == "cargo test"));
+        let warnings = rust.warnings(&temp_dir.dir);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("Makefile.toml"));
+    }
 }
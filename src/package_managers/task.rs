@@ -0,0 +1,193 @@
+use anyhow::Result;
+use serde::Deserialize;
+use serde_yaml::{Mapping, Value};
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use super::PackageManager;
+use crate::types::{Script, ScriptType};
+
+const TASKFILE_NAMES: &[&str] = &[
+    "Taskfile.yml",
+    "taskfile.yml",
+    "Taskfile.yaml",
+    "taskfile.yaml",
+    "Taskfile.dist.yml",
+    "taskfile.dist.yml",
+    "Taskfile.dist.yaml",
+    "taskfile.dist.yaml",
+];
+
+pub struct TaskPackageManager;
+
+#[derive(Deserialize)]
+struct Taskfile {
+    #[serde(default)]
+    tasks: Mapping,
+}
+
+/// A task definition, in any of the forms go-task accepts: a single command,
+/// a list of commands, or a full task object.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TaskEntry {
+    Command(String),
+    Commands(Vec<Value>),
+    Task(TaskDefinition),
+}
+
+#[derive(Default, Deserialize)]
+struct TaskDefinition {
+    desc: Option<String>,
+    summary: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    internal: bool,
+    #[serde(default)]
+    cmds: Vec<Value>,
+}
+
+impl TaskEntry {
+    fn into_definition(self) -> TaskDefinition {
+        match self {
+            Self::Command(cmd) => TaskDefinition {
+                cmds: vec![Value::String(cmd)],
+                ..Default::default()
+            },
+            Self::Commands(cmds) => TaskDefinition {
+                cmds,
+                ..Default::default()
+            },
+            Self::Task(task) => task,
+        }
+    }
+}
+
+impl TaskDefinition {
+    /// Returns the shell commands of the task, for classification. Commands
+    /// may be plain strings or `{cmd: ...}` / `{task: ...}` objects.
+    fn commands(&self) -> Vec<&str> {
+        self.cmds
+            .iter()
+            .filter_map(|cmd| match cmd {
+                Value::String(s) => Some(s.as_str()),
+                Value::Mapping(m) => m
+                    .get("cmd")
+                    .or_else(|| m.get("task"))
+                    .and_then(|v| v.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn description(&self) -> Option<String> {
+        let description = self
+            .desc
+            .clone()
+            .or_else(|| self.summary.as_ref().map(|s| s.trim().to_string()))?;
+        if self.aliases.is_empty() {
+            Some(description)
+        } else {
+            Some(format!(
+                "{} (aliases: {})",
+                description,
+                self.aliases.join(", ")
+            ))
+        }
+    }
+}
+
+impl PackageManager for TaskPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        find_taskfile(dir).map(|_| TaskPackageManager)
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "task"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("task");
+        cmd.arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let taskfile_path =
+            find_taskfile(path).ok_or_else(|| anyhow::anyhow!("Taskfile not found"))?;
+        let content = fs::read_to_string(taskfile_path)?;
+        let taskfile: Taskfile = serde_yaml::from_str(&content)?;
+
+        let mut scripts = Vec::new();
+        for (name, entry) in taskfile.tasks {
+            let Some(name) = name.as_str() else {
+                continue;
+            };
+            let task = serde_yaml::from_value::<TaskEntry>(entry)
+                .map(TaskEntry::into_definition)
+                .unwrap_or_default();
+
+            // Internal tasks can't be invoked from the command line
+            if task.internal {
+                continue;
+            }
+
+            let script_type = ScriptType::from_script(name, &task.commands().join("\n"));
+            scripts.push(
+                Script::new(
+                    name,
+                    &format!("task {}", name),
+                    task.description(),
+                    Some(script_type),
+                    None,
+                )
+                .with_aliases(task.aliases),
+            );
+        }
+
+        Ok(scripts)
+    }
+}
+
+fn find_taskfile(dir: &Path) -> Option<PathBuf> {
+    TASKFILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+    use crate::types::find_synonym_script;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir = create_task_project(&std::env::temp_dir().join("task-project")).unwrap();
+        let task = TaskPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = task.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(names, ["build", "serve", "test", "lint"]);
+        assert!(scripts.iter().any(|s| s.name == "build"
+            && s.command == "task build"
+            && s.script_type == ScriptType::Build
+            && s.description.as_deref() == Some("Build the app")));
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Runs the whole test suite.")));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "lint" && s.script_type == ScriptType::Lint));
+        assert_eq!(
+            find_synonym_script(&scripts, "dev"),
+            Some("serve".to_string())
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use anyhow::Result;
+use toml::Value;
+
+use std::{collections::HashMap, fs, path::Path, process::Command};
+
+use super::PackageManager;
+use crate::types::{Script, ScriptType};
+
+pub struct ToxPackageManager;
+
+struct ToxEnv {
+    name: String,
+    description: Option<String>,
+    commands: String,
+}
+
+impl PackageManager for ToxPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("tox.ini").exists() || dir.join("tox.toml").exists() {
+            return Some(ToxPackageManager);
+        }
+        fs::read_to_string(dir.join("pyproject.toml"))
+            .ok()
+            .and_then(|content| content.parse::<Value>().ok())
+            .and_then(|pyproject| pyproject.get("tool")?.get("tox").cloned())
+            .map(|_| ToxPackageManager)
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "tox"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("tox");
+        cmd.arg("-e").arg(script);
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let envs = if let Ok(content) = fs::read_to_string(path.join("tox.ini")) {
+            parse_tox_ini(&content)
+        } else if let Ok(content) = fs::read_to_string(path.join("tox.toml")) {
+            parse_tox_toml(&toml::from_str(&content)?)
+        } else {
+            let content = fs::read_to_string(path.join("pyproject.toml"))?;
+            let pyproject: Value = toml::from_str(&content)?;
+            let tox = pyproject
+                .get("tool")
+                .and_then(|t| t.get("tox"))
+                .ok_or_else(|| anyhow::anyhow!("tox configuration not found"))?;
+            match tox.get("legacy_tox_ini").and_then(|ini| ini.as_str()) {
+                Some(ini) => parse_tox_ini(ini),
+                None => parse_tox_toml(tox),
+            }
+        };
+
+        Ok(envs
+            .into_iter()
+            .map(|env| {
+                Script::new(
+                    &env.name,
+                    &format!("tox -e {}", env.name),
+                    env.description,
+                    Some(env_script_type(&env.name, &env.commands)),
+                    None,
+                )
+            })
+            .collect())
+    }
+}
+
+/// Environments named after a Python version (`py312`, `3.12`, `pypy3`, ...)
+/// run the test suite; others are classified by name and commands.
+fn env_script_type(name: &str, commands: &str) -> ScriptType {
+    let factor = name.split('-').next().unwrap_or(name);
+    let is_version = |s: &str| s.chars().all(|c| c.is_ascii_digit() || c == '.');
+    let is_python_version = match factor.strip_prefix("py") {
+        Some(version) => is_version(version.trim_start_matches("py")),
+        None => is_version(factor),
+    };
+    if is_python_version {
+        ScriptType::Test
+    } else {
+        ScriptType::from_script(name, commands)
+    }
+}
+
+/// Parses the `envlist` of the `[tox]` section and any `[testenv:name]`
+/// sections of a tox.ini file.
+fn parse_tox_ini(content: &str) -> Vec<ToxEnv> {
+    let sections = parse_ini(content);
+    let mut envs: Vec<ToxEnv> = Vec::new();
+
+    let env_list = sections
+        .get("tox")
+        .and_then(|tox| tox.get("envlist").or_else(|| tox.get("env_list")));
+    if let Some(env_list) = env_list {
+        for name in split_env_list(env_list) {
+            add_env(&mut envs, &name, &sections);
+        }
+    }
+
+    let mut section_names: Vec<&String> = sections.keys().collect();
+    section_names.sort();
+    for section in section_names {
+        if let Some(name) = section.strip_prefix("testenv:") {
+            for name in expand_braces(name.trim()) {
+                add_env(&mut envs, &name, &sections);
+            }
+        }
+    }
+
+    envs
+}
+
+fn add_env(
+    envs: &mut Vec<ToxEnv>,
+    name: &str,
+    sections: &HashMap<String, HashMap<String, String>>,
+) {
+    if envs.iter().any(|e| e.name == name) {
+        return;
+    }
+    let section = sections.get(&format!("testenv:{}", name));
+    let base = sections.get("testenv");
+    let setting = |key: &str| {
+        section
+            .and_then(|s| s.get(key))
+            .or_else(|| base.and_then(|s| s.get(key)))
+            .cloned()
+    };
+    envs.push(ToxEnv {
+        name: name.to_string(),
+        description: setting("description"),
+        commands: setting("commands").unwrap_or_default(),
+    });
+}
+
+/// Parses the native TOML configuration of tox 4, found in tox.toml or in
+/// `[tool.tox]`: an `env_list` array and `[env.<name>]` tables.
+fn parse_tox_toml(tox: &Value) -> Vec<ToxEnv> {
+    let env_tables = tox.get("env").and_then(|e| e.as_table());
+    let mut names: Vec<String> = tox
+        .get("env_list")
+        .and_then(|l| l.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|name| name.as_str())
+        .map(|name| name.to_string())
+        .collect();
+    for name in env_tables.into_iter().flat_map(|envs| envs.keys()) {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            let env = env_tables.and_then(|envs| envs.get(&name));
+            let commands = env
+                .and_then(|e| e.get("commands"))
+                .and_then(|c| c.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|command| command.as_array())
+                .map(|args| {
+                    args.iter()
+                        .filter_map(|arg| arg.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            ToxEnv {
+                description: env
+                    .and_then(|e| e.get("description"))
+                    .and_then(|d| d.as_str())
+                    .map(|d| d.to_string()),
+                name,
+                commands,
+            }
+        })
+        .collect()
+}
+
+/// Parses an INI file into sections of key/value pairs. Indented lines
+/// continue the value of the preceding key.
+fn parse_ini(content: &str) -> HashMap<String, HashMap<String, String>> {
+    let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut section = String::new();
+    let mut key: Option<String> = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) {
+            if let Some(value) = key
+                .as_ref()
+                .and_then(|k| sections.get_mut(&section)?.get_mut(k))
+            {
+                value.push('\n');
+                value.push_str(trimmed);
+            }
+            continue;
+        }
+        if let Some(name) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            section = name.trim().to_string();
+            key = None;
+            continue;
+        }
+        if let Some((k, v)) = trimmed.split_once('=') {
+            let k = k.trim().to_string();
+            sections
+                .entry(section.clone())
+                .or_default()
+                .insert(k.clone(), v.trim().to_string());
+            key = Some(k);
+        }
+    }
+
+    sections
+}
+
+/// Splits an envlist on commas and newlines outside braces, expanding
+/// generative names such as `py{311,312}-django{4,5}`.
+fn split_env_list(env_list: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in env_list.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' | '\n' if depth == 0 => {
+                names.extend(expand_braces(current.trim()));
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    names.extend(expand_braces(current.trim()));
+    names.retain(|name| !name.is_empty());
+    names
+}
+
+fn expand_braces(name: &str) -> Vec<String> {
+    let Some(open) = name.find('{') else {
+        return vec![name.to_string()];
+    };
+    let Some(close) = name[open..].find('}').map(|i| open + i) else {
+        return vec![name.to_string()];
+    };
+    let (prefix, alternatives, suffix) =
+        (&name[..open], &name[open + 1..close], &name[close + 1..]);
+    alternatives
+        .split(',')
+        .flat_map(|alternative| {
+            expand_braces(&format!("{}{}{}", prefix, alternative.trim(), suffix))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir = create_tox_project(&std::env::temp_dir().join("tox-project")).unwrap();
+        let tox = ToxPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = tox.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            [
+                "py311-django4",
+                "py311-django5",
+                "py312-django4",
+                "py312-django5",
+                "lint",
+                "docs"
+            ]
+        );
+        assert!(scripts.iter().any(|s| s.name == "py312-django5"
+            && s.command == "tox -e py312-django5"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Run the test suite")));
+        assert!(scripts.iter().any(|s| s.name == "lint"
+            && s.script_type == ScriptType::Lint
+            && s.description.as_deref() == Some("Check code style")));
+        assert!(scripts
+            .iter()
+            .any(|s| s.name == "docs" && s.script_type == ScriptType::Build));
+    }
+}
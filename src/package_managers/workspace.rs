@@ -0,0 +1,149 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::WorkspaceMember;
+
+/// Expands a workspace member path into the matching directories under
+/// `root`. Path segments may contain `*` and `?` wildcards (e.g.
+/// `packages/*`), and a `**` segment matches any number of directories.
+/// Hidden directories and `node_modules` aren't searched by wildcards.
+pub(super) fn expand_workspace_pattern(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut dirs = vec![root.to_path_buf()];
+    for segment in pattern.split('/').filter(|s| !s.is_empty() && *s != ".") {
+        dirs = if segment == "**" {
+            let mut descendants = Vec::new();
+            for dir in dirs {
+                collect_descendants(&dir, &mut descendants);
+            }
+            descendants
+        } else if segment.contains(['*', '?']) {
+            dirs.iter()
+                .flat_map(|dir| subdirectories(dir))
+                .filter(|dir| {
+                    let name = dir.file_name().unwrap_or_default().to_string_lossy();
+                    wildcard_match(segment, &name)
+                })
+                .collect()
+        } else {
+            dirs.into_iter().map(|dir| dir.join(segment)).collect()
+        };
+        dirs.sort();
+        dirs.dedup();
+    }
+    dirs.into_iter().filter(|dir| dir.is_dir()).collect()
+}
+
+fn subdirectories(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            !name.starts_with('.') && name != "node_modules"
+        })
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect()
+}
+
+/// Collects `dir` and all the directories below it.
+fn collect_descendants(dir: &Path, descendants: &mut Vec<PathBuf>) {
+    descendants.push(dir.to_path_buf());
+    for subdir in subdirectories(dir) {
+        collect_descendants(&subdir, descendants);
+    }
+}
+
+/// Matches a file name against a pattern with `*` and `?` wildcards.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    match pattern.chars().next() {
+        None => name.is_empty(),
+        Some('*') => (0..=name.len())
+            .filter(|&i| name.is_char_boundary(i))
+            .any(|i| wildcard_match(&pattern[1..], &name[i..])),
+        Some(c) => name.chars().next().is_some_and(|n| {
+            (c == '?' || c == n) && wildcard_match(&pattern[c.len_utf8()..], &name[n.len_utf8()..])
+        }),
+    }
+}
+
+/// Orders workspace members so that each comes after the members it depends
+/// on, keeping the original order where dependencies allow. Members in a
+/// dependency cycle are placed at the end, in their original order.
+pub fn dependency_order(members: Vec<WorkspaceMember>) -> Vec<WorkspaceMember> {
+    let names: Vec<String> = members.iter().map(|m| m.name.clone()).collect();
+    let mut remaining = members;
+    let mut ordered: Vec<WorkspaceMember> = Vec::new();
+
+    while !remaining.is_empty() {
+        let ready = remaining.iter().position(|member| {
+            member.dependencies.iter().all(|dependency| {
+                !names.contains(dependency)
+                    || *dependency == member.name
+                    || ordered.iter().any(|m| m.name == *dependency)
+            })
+        });
+        match ready {
+            Some(index) => ordered.push(remaining.remove(index)),
+            None => ordered.append(&mut remaining),
+        }
+    }
+
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::package_managers::PackageManager;
+
+    struct NullPackageManager;
+
+    impl PackageManager for NullPackageManager {
+        fn detect(_dir: &Path) -> Option<Self> {
+            None
+        }
+
+        fn tool_name(&self) -> &'static str {
+            "null"
+        }
+
+        fn find_scripts(&self, _path: &Path) -> anyhow::Result<Vec<crate::types::Script>> {
+            Ok(Vec::new())
+        }
+
+        fn run_command(&self, script: &str) -> std::process::Command {
+            std::process::Command::new(script)
+        }
+    }
+
+    fn member(name: &str, dependencies: &[&str]) -> WorkspaceMember {
+        WorkspaceMember {
+            name: name.to_string(),
+            path: PathBuf::from(name),
+            package_manager: Box::new(NullPackageManager),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_dependency_order() {
+        let members = vec![
+            member("app", &["api", "ui", "react"]),
+            member("api", &["core"]),
+            member("core", &[]),
+            member("ui", &["core"]),
+            member("cycle-a", &["cycle-b"]),
+            member("cycle-b", &["cycle-a"]),
+        ];
+        let names: Vec<String> = dependency_order(members)
+            .into_iter()
+            .map(|member| member.name)
+            .collect();
+
+        assert_eq!(names, ["core", "api", "ui", "app", "cycle-a", "cycle-b"]);
+    }
+}
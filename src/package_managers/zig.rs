@@ -0,0 +1,114 @@
+use anyhow::Result;
+
+use std::{fs, path::Path, process::Command};
+
+use super::PackageManager;
+use crate::types::{Script, ScriptType};
+
+pub struct ZigPackageManager;
+
+impl PackageManager for ZigPackageManager {
+    fn detect(dir: &Path) -> Option<Self> {
+        if dir.join("build.zig").exists() {
+            Some(ZigPackageManager)
+        } else {
+            None
+        }
+    }
+
+    fn tool_name(&self) -> &'static str {
+        "zig"
+    }
+
+    fn run_command(&self, script: &str) -> Command {
+        let mut cmd = Command::new("zig");
+        cmd.arg("build");
+        // `zig build` alone runs the default install step
+        if script != "build" {
+            cmd.arg(script);
+        }
+        cmd
+    }
+
+    fn find_scripts(&self, path: &Path) -> Result<Vec<Script>> {
+        let content = fs::read_to_string(path.join("build.zig"))?;
+
+        let mut scripts = vec![Script::new(
+            "build",
+            "zig build",
+            Some("Build and install the artifacts".to_string()),
+            Some(ScriptType::Build),
+            Some('b'),
+        )];
+        for (name, description) in parse_steps(&content) {
+            if scripts.iter().any(|s| s.name == name) {
+                continue;
+            }
+            scripts.push(Script::new(
+                &name,
+                &format!("zig build {}", name),
+                Some(description),
+                Some(ScriptType::from_script(&name, "")),
+                None,
+            ));
+        }
+        Ok(scripts)
+    }
+}
+
+/// Finds the top-level steps declared with `b.step("name", "description")`,
+/// which `zig build -l` lists.
+fn parse_steps(content: &str) -> Vec<(String, String)> {
+    let mut steps = Vec::new();
+    let mut rest = content;
+    while let Some(start) = rest.find(".step(") {
+        rest = &rest[start + ".step(".len()..];
+        let Some((name, after_name)) = string_literal(rest) else {
+            continue;
+        };
+        let Some(after_comma) = after_name.trim_start().strip_prefix(',') else {
+            continue;
+        };
+        let Some((description, _)) = string_literal(after_comma) else {
+            continue;
+        };
+        steps.push((name, description));
+    }
+    steps
+}
+
+/// Reads a double-quoted string at the start of `text`, ignoring leading
+/// whitespace.
+fn string_literal(text: &str) -> Option<(String, &str)> {
+    let text = text.trim_start().strip_prefix('"')?;
+    let end = text.find('"')?;
+    Some((text[..end].to_string(), &text[end + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+
+    #[test]
+    fn test_find_scripts() {
+        let temp_dir = create_zig_project(&std::env::temp_dir().join("zig-project")).unwrap();
+        let zig = ZigPackageManager::detect(&temp_dir.dir).unwrap();
+        let scripts = zig.find_scripts(&temp_dir.dir).unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(names, ["build", "run", "test", "docs"]);
+        assert!(scripts.iter().any(|s| s.name == "run"
+            && s.command == "zig build run"
+            && s.description.as_deref() == Some("Run the app")));
+        assert!(scripts.iter().any(|s| s.name == "test"
+            && s.script_type == ScriptType::Test
+            && s.description.as_deref() == Some("Run unit tests")));
+
+        let command = zig.script_command(&scripts[1]);
+        assert_eq!(command.get_program(), "zig");
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["build", "run"]);
+        let command = zig.script_command(&scripts[0]);
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["build"]);
+    }
+}
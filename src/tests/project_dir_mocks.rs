@@ -1,7 +1,7 @@
 use anyhow::Result;
 use serde_json::json;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct TestProject {
     pub dir: PathBuf,
@@ -81,6 +81,839 @@ pub fn create_pnpm_project(dir: &PathBuf) -> Result<TestProject> {
     Ok(project)
 }
 
+pub fn create_cargo_alias_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        ".cargo/config.toml",
+        r#"
+[alias]
+xtask = "run --package xtask --"
+ci = ["test", "--workspace", "--locked"]
+lint-all = "clippy --workspace"
+lint = "clippy -- -D warnings"
+build = "build --release"
+"#,
+    )?;
+
+    project.create_file(
+        "member/Cargo.toml",
+        r#"
+[package]
+name = "member"
+version = "0.1.0"
+"#,
+    )?;
+
+    project.create_file(
+        "member/.cargo/config.toml",
+        r#"
+[alias]
+lint-all = "clippy --all-targets"
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_cargo_workspace_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "Cargo.toml",
+        r#"
+[workspace]
+members = ["crates/*", "tools/xtask", "tools/site docs"]
+exclude = ["crates/experimental"]
+"#,
+    )?;
+
+    project.create_file(
+        "crates/core/Cargo.toml",
+        r#"
+[package]
+name = "app-core"
+version = "0.1.0"
+"#,
+    )?;
+    project.create_file("crates/core/src/lib.rs", "")?;
+
+    project.create_file(
+        "crates/cli/Cargo.toml",
+        r#"
+[package]
+name = "app-cli"
+version = "0.1.0"
+
+[package.metadata.scripts]
+dev = "cargo watch -x run"
+
+[[bin]]
+name = "app-admin"
+path = "src/admin.rs"
+"#,
+    )?;
+    project.create_file("crates/cli/src/main.rs", "fn main() {}")?;
+
+    project.create_file(
+        "crates/experimental/Cargo.toml",
+        r#"
+[package]
+name = "app-experimental"
+version = "0.1.0"
+"#,
+    )?;
+
+    project.create_file(
+        "tools/xtask/Cargo.toml",
+        r#"
+[package]
+name = "xtask"
+version = "0.1.0"
+"#,
+    )?;
+    project.create_file("tools/xtask/src/main.rs", "fn main() {}")?;
+
+    project.create_file(
+        "tools/site docs/Cargo.toml",
+        r#"
+[package]
+name = "site-docs"
+version = "0.1.0"
+
+[package.metadata.scripts]
+serve = "mdbook serve"
+"#,
+    )?;
+    project.create_file("tools/site docs/src/lib.rs", "")?;
+
+    Ok(project)
+}
+
+pub fn create_cargo_targets_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "Cargo.toml",
+        r#"
+[package]
+name = "targets"
+version = "0.1.0"
+
+[[bin]]
+name = "server"
+path = "src/main.rs"
+
+[[example]]
+name = "gui"
+required-features = ["gui", "images"]
+
+[[bench]]
+name = "throughput"
+harness = false
+"#,
+    )?;
+
+    project.create_file("src/main.rs", "fn main() {}")?;
+    project.create_file("src/bin/migrate.rs", "fn main() {}")?;
+    project.create_file("src/bin/seed/main.rs", "fn main() {}")?;
+    project.create_file("examples/hello.rs", "fn main() {}")?;
+    project.create_file("examples/gui.rs", "fn main() {}")?;
+    project.create_file("examples/README.md", "")?;
+    project.create_file("benches/parse.rs", "")?;
+
+    Ok(project)
+}
+
+pub fn create_cargo_make_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "Cargo.toml",
+        r#"
+[package]
+name = "cargo-make-test"
+version = "0.1.0"
+"#,
+    )?;
+
+    project.create_file(
+        "Makefile.toml",
+        r#"
+[env]
+CARGO_MAKE_EXTEND_WORKSPACE_MAKEFILE = true
+
+[tasks.test]
+description = "Run the tests with coverage"
+category = "Test"
+command = "cargo"
+args = ["llvm-cov"]
+
+[tasks.ci-flow]
+description = "Run the CI checks"
+dependencies = ["format-check", "test"]
+
+[tasks.format-check]
+command = "cargo"
+args = ["fmt", "--check"]
+
+[tasks.docs]
+category = "Build"
+script = ["cargo doc --no-deps"]
+
+[tasks.setup-hooks]
+private = true
+script = "git config core.hooksPath .githooks"
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_go_module_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file("go.mod", "module github.com/example/service\n\ngo 1.22\n")?;
+    project.create_file("cmd/server/main.go", "package main\n\nfunc main() {}\n")?;
+    project.create_file("cmd/migrate/main.go", "package main\n\nfunc main() {}\n")?;
+    project.create_file("internal/auth/auth.go", "package auth\n")?;
+    project.create_file("internal/auth/auth_test.go", "package auth\n")?;
+    project.create_file(
+        "models/models.go",
+        "package models\n\n//go:generate stringer -type=Kind\ntype Kind int\n",
+    )?;
+    project.create_file(
+        "models/gen.go",
+        "//go:build ignore\n\npackage main\n\nfunc main() {}\n",
+    )?;
+    project.create_file("vendor/example.com/tool/main.go", "package main\n")?;
+    project.create_file("node_modules/esbuild/cmd/main.go", "package main\n")?;
+    project.create_file("util_test.go", "package service\n")?;
+
+    Ok(project)
+}
+
+pub fn create_mage_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file("go.mod", "module github.com/example/infra\n\ngo 1.22\n")?;
+    project.create_file(
+        "magefile.go",
+        r#"//go:build mage
+
+package main
+
+import "github.com/magefile/mage/mg"
+
+type Docker mg.Namespace
+
+// Build compiles the binary. It embeds the version.
+func Build() error {
+	return nil
+}
+
+// Test runs the test suite.
+func Test(ctx context.Context) error {
+	return nil
+}
+
+// Push pushes the image to the registry.
+func (Docker) Push() error {
+	return nil
+}
+
+func (d Docker) BuildImage() error {
+	return nil
+}
+
+// helper isn't a target.
+func helper() {}
+"#,
+    )?;
+    project.create_file("tools.go", "//go:build tools\n\npackage tools\n")?;
+
+    Ok(project)
+}
+
+pub fn create_npm_workspace_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "package.json",
+        &json!({
+            "name": "monorepo",
+            "private": true,
+            "workspaces": ["packages/*", "!packages/legacy"],
+            "scripts": {
+                "lint": "eslint ."
+            }
+        })
+        .to_string(),
+    )?;
+    project.create_file("package-lock.json", "{}")?;
+
+    project.create_file(
+        "packages/web/package.json",
+        &json!({
+            "name": "@acme/web",
+            "scripts": {
+                "build": "vite build",
+                "dev": "vite"
+            }
+        })
+        .to_string(),
+    )?;
+    project.create_file(
+        "packages/api/package.json",
+        &json!({
+            "name": "api",
+            "scripts": {
+                "test": "vitest"
+            }
+        })
+        .to_string(),
+    )?;
+    project.create_file(
+        "packages/legacy/package.json",
+        &json!({
+            "name": "legacy",
+            "scripts": {
+                "build": "gulp"
+            }
+        })
+        .to_string(),
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_turbo_nx_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "package.json",
+        &json!({
+            "name": "frontend",
+            "private": true,
+            "scripts": { "build": "turbo run build" }
+        })
+        .to_string(),
+    )?;
+    project.create_file("package-lock.json", "{}")?;
+
+    project.create_file(
+        "turbo.json",
+        r#"{
+  // Turborepo 2 configuration
+  "$schema": "https://turbo.build/schema.json",
+  "tasks": {
+    "build": { "dependsOn": ["^build"], "outputs": ["dist/**"] },
+    "test": { "dependsOn": ["build"] },
+    "web#deploy": { "dependsOn": ["web#build", "test"] },
+    "//#lint": {},
+  }
+}"#,
+    )?;
+
+    project.create_file(
+        "nx.json",
+        &json!({
+            "targetDefaults": {
+                "build": { "dependsOn": ["^build"] }
+            }
+        })
+        .to_string(),
+    )?;
+    project.create_file(
+        "apps/web/project.json",
+        &json!({
+            "name": "web",
+            "targets": {
+                "build": { "executor": "@nx/vite:build" },
+                "serve": {
+                    "executor": "@nx/vite:dev-server",
+                    "metadata": { "description": "Serve the web app" },
+                    "dependsOn": [{ "target": "codegen", "dependencies": true }]
+                }
+            }
+        })
+        .to_string(),
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_corepack_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    // The packageManager field takes precedence over a stray lock file
+    project.create_file(
+        "pinned/package.json",
+        &json!({
+            "name": "pinned",
+            "packageManager": "pnpm@9.1.0+sha512.abc123",
+            "workspaces": ["packages/*"]
+        })
+        .to_string(),
+    )?;
+    project.create_file("pinned/package-lock.json", "{}")?;
+    project.create_file(
+        "pinned/packages/ui/package.json",
+        &json!({ "name": "ui", "scripts": { "build": "tsc" } }).to_string(),
+    )?;
+
+    // A fresh clone, with neither a lock file nor a packageManager field,
+    // below a directory with a stray package.json and lock file, such as a
+    // home directory
+    project.create_file(
+        "plain/package.json",
+        &json!({ "name": "plain", "scripts": { "test": "node --test" } }).to_string(),
+    )?;
+    project.create_file("plain/.git/HEAD", "ref: refs/heads/main\n")?;
+    project.create_file(
+        "package.json",
+        &json!({ "packageManager": "yarn@4.1.0" }).to_string(),
+    )?;
+    project.create_file("yarn.lock", "")?;
+
+    Ok(project)
+}
+
+pub fn create_lock_conflict_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    // Left behind after switching from npm to yarn
+    project.create_file(
+        "node/package.json",
+        &json!({ "name": "node", "scripts": { "test": "jest" } }).to_string(),
+    )?;
+    project.create_file("node/package-lock.json", "{}")?;
+    project.create_file("node/yarn.lock", "")?;
+
+    // Left behind after switching from poetry to uv
+    project.create_file(
+        "python/pyproject.toml",
+        r#"[project]
+name = "app"
+
+[tool.uv]
+dev-dependencies = ["pytest"]
+"#,
+    )?;
+    project.create_file("python/poetry.lock", "")?;
+    project.create_file("python/uv.lock", "")?;
+
+    Ok(project)
+}
+
+pub fn create_polyglot_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    // A Tauri-style app: a web frontend with a Rust backend
+    project.create_file(
+        "package.json",
+        &json!({
+            "name": "app",
+            "scripts": {
+                "dev": "vite",
+                "build": "vite build",
+                "test": "vitest"
+            }
+        })
+        .to_string(),
+    )?;
+    project.create_file(
+        "Cargo.toml",
+        r#"[package]
+name = "app"
+version = "0.1.0"
+edition = "2021"
+"#,
+    )?;
+    project.create_file("src/main.rs", "fn main() {}\n")?;
+
+    Ok(project)
+}
+
+pub fn create_rake_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file("Gemfile", "source \"https://rubygems.org\"\n\ngem \"rake\"\n")?;
+    project.create_file("Gemfile.lock", "GEM\n  specs:\n    rake (13.2.1)\n")?;
+    project.create_file(
+        "Rakefile",
+        r#"require "rake/testtask"
+
+Rake::TestTask.new(:test) do |t|
+  t.libs << "test"
+end
+
+desc "Run the linter"
+task :lint do
+  sh "rubocop"
+end
+
+task default: [:lint, :test]
+
+namespace :db do
+  desc "Load the seed data"
+  task seed: :environment do
+    ruby "db/seeds.rb"
+  end
+
+  namespace :cache do
+    desc "Clear the query cache"
+    task :clear, [:scope] => :environment do |t, args|
+      if args[:scope]
+        puts "Clearing #{args[:scope]}"
+      end
+    end
+  end
+end
+
+task :assets
+"#,
+    )?;
+    project.create_file(
+        "lib/tasks/cleanup.rake",
+        r#"namespace :cleanup do
+  desc "Remove expired sessions"
+  task sessions: :environment do
+    Session.expired.delete_all
+  end
+end
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_composer_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "composer.json",
+        &json!({
+            "name": "acme/app",
+            "require-dev": { "phpunit/phpunit": "^11.0" },
+            "scripts": {
+                "test": "phpunit --colors=always",
+                "analyse": "phpstan analyse src",
+                "cs-fix": "php-cs-fixer fix",
+                "check": ["@cs-fix --dry-run", "@analyse", "@test"],
+                "post-install-cmd": "@php artisan key:generate"
+            },
+            "scripts-descriptions": {
+                "test": "Run the PHPUnit test suite"
+            },
+            "scripts-aliases": {
+                "test": ["t"]
+            }
+        })
+        .to_string(),
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_mix_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    // As generated by `mix phx.new`
+    project.create_file(
+        "mix.exs",
+        r#"defmodule Hello.MixProject do
+  use Mix.Project
+
+  def project do
+    [
+      app: :hello,
+      version: "0.1.0",
+      elixir: "~> 1.14",
+      deps: deps(),
+      aliases: aliases()
+    ]
+  end
+
+  defp deps do
+    [
+      {:phoenix, "~> 1.7.14"},
+      {:ecto_sql, "~> 3.10"},
+      {:credo, "~> 1.7", only: [:dev, :test], runtime: false}
+    ]
+  end
+
+  # Aliases are shortcuts or tasks specific to the current project.
+  # See the documentation for `Mix` for more info on aliases.
+  defp aliases do
+    [
+      setup: ["deps.get", "ecto.setup"],
+      "ecto.setup": ["ecto.create", "ecto.migrate", "run priv/repo/seeds.exs"],
+      "ecto.reset": ["ecto.drop", "ecto.setup"],
+      test: ["ecto.create --quiet", "ecto.migrate --quiet", "test"],
+      "assets.deploy": ["tailwind hello --minify", "esbuild hello --minify", "phx.digest"],
+      lint: "credo --strict"
+    ]
+  end
+end
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_maven_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file("mvnw", "#!/bin/sh\n")?;
+    project.create_file(
+        "pom.xml",
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <modelVersion>4.0.0</modelVersion>
+  <groupId>com.example</groupId>
+  <artifactId>app</artifactId>
+  <version>1.0.0</version>
+
+  <build>
+    <plugins>
+      <plugin>
+        <groupId>org.apache.maven.plugins</groupId>
+        <artifactId>maven-surefire-plugin</artifactId>
+        <executions>
+          <execution>
+            <id>not-exec</id>
+          </execution>
+        </executions>
+      </plugin>
+      <plugin>
+        <groupId>org.codehaus.mojo</groupId>
+        <artifactId>exec-maven-plugin</artifactId>
+        <version>3.1.0</version>
+        <executions>
+          <execution>
+            <id>seed</id>
+            <goals><goal>java</goal></goals>
+            <configuration>
+              <mainClass>com.example.Seed</mainClass>
+            </configuration>
+          </execution>
+          <!-- <execution><id>disabled</id></execution> -->
+          <execution>
+            <id>codegen</id>
+            <configuration>
+              <executable>protoc</executable>
+            </configuration>
+          </execution>
+        </executions>
+      </plugin>
+    </plugins>
+  </build>
+
+  <profiles>
+    <profile>
+      <id>native</id>
+      <repositories>
+        <repository><id>not-a-profile</id></repository>
+      </repositories>
+    </profile>
+    <profile>
+      <id>release</id>
+    </profile>
+  </profiles>
+</project>
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_gradle_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "settings.gradle.kts",
+        r#"rootProject.name = "demo"
+include("app")
+include(":libs:core")
+"#,
+    )?;
+    project.create_file(
+        "build.gradle.kts",
+        r#"tasks.register<Javadoc>("generateDocs") {
+    group = "documentation"
+    description = "Generates the API documentation"
+}
+"#,
+    )?;
+    project.create_file(
+        "app/build.gradle.kts",
+        r#"plugins {
+    application
+}
+
+application {
+    mainClass.set("com.example.AppKt")
+}
+
+tasks.register<Test>("integrationTest") {
+    description = "Runs the integration tests"
+    useJUnitPlatform()
+}
+"#,
+    )?;
+    project.create_file(
+        "libs/core/build.gradle",
+        r#"task printVersion(type: Exec, dependsOn: 'jar') {
+    commandLine 'echo', version
+}
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_zig_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    // As generated by `zig init`
+    project.create_file(
+        "build.zig",
+        r#"const std = @import("std");
+
+pub fn build(b: *std.Build) void {
+    const target = b.standardTargetOptions(.{});
+    const optimize = b.standardOptimizeOption(.{});
+
+    const exe = b.addExecutable(.{
+        .name = "hello",
+        .root_source_file = b.path("src/main.zig"),
+        .target = target,
+        .optimize = optimize,
+    });
+    b.installArtifact(exe);
+
+    const run_cmd = b.addRunArtifact(exe);
+    const run_step = b.step("run", "Run the app");
+    run_step.dependOn(&run_cmd.step);
+
+    const exe_unit_tests = b.addTest(.{
+        .root_source_file = b.path("src/main.zig"),
+    });
+    const test_step = b.step("test", "Run unit tests");
+    test_step.dependOn(&b.addRunArtifact(exe_unit_tests).step);
+
+    const docs_step = b.step(
+        "docs",
+        "Generate the documentation",
+    );
+    docs_step.dependOn(&exe.step);
+}
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_cmake_presets_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file("CMakeLists.txt", "cmake_minimum_required(VERSION 3.25)\n")?;
+    project.create_file(
+        "CMakePresets.json",
+        &json!({
+            "version": 6,
+            "configurePresets": [
+                { "name": "base", "hidden": true, "binaryDir": "build/${presetName}" },
+                {
+                    "name": "debug",
+                    "inherits": "base",
+                    "displayName": "Debug",
+                    "description": "Debug build with sanitizers"
+                },
+                { "name": "release", "inherits": "base", "displayName": "Release" }
+            ],
+            "buildPresets": [{ "name": "debug", "configurePreset": "debug" }],
+            "testPresets": [{ "name": "debug", "configurePreset": "debug" }]
+        })
+        .to_string(),
+    )?;
+    project.create_file(
+        "CMakeUserPresets.json",
+        &json!({
+            "version": 6,
+            "configurePresets": [{ "name": "local", "inherits": "debug" }]
+        })
+        .to_string(),
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_deno_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "deno.jsonc",
+        r#"{
+  // Tasks run with `deno task <name>`
+  "tasks": {
+    "dev": "deno run --watch main.ts",
+    "check": "deno check main.ts", /* type check only */
+    "test": {
+      "command": "deno test",
+      "description": "Run the tests",
+      "dependencies": ["check"],
+    },
+    "ci": {
+      "dependencies": ["check", "test"]
+    },
+  },
+}
+"#,
+    )?;
+
+    Ok(project)
+}
+
 pub fn create_cargo_project(dir: &PathBuf) -> Result<TestProject> {
     let project = TestProject { dir: dir.clone() };
 
@@ -125,6 +958,255 @@ ruff = "^0.1.0"
     Ok(project)
 }
 
+pub fn create_poe_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "pyproject.toml",
+        r#"
+[tool.poetry]
+name = "poe-test"
+version = "0.1.0"
+
+[tool.poetry.dependencies]
+python = "^3.11"
+
+[tool.poetry.group.dev.dependencies]
+poethepoet = "^0.24"
+ruff = "^0.1.0"
+
+[tool.poetry.scripts]
+serve = "app.server:main"
+migrate = { callable = "app.db:migrate" }
+
+[tool.poe.tasks]
+format = "ruff format ."
+check = ["format", "test"]
+
+[tool.poe.tasks.test]
+cmd = "pytest tests"
+help = "Run the test suite"
+
+[tool.poe.tasks.ci]
+ref = "check"
+"#,
+    )?;
+
+    project.create_file("poetry.lock", "")?;
+
+    Ok(project)
+}
+
+pub fn create_uv_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "pyproject.toml",
+        r#"
+[project]
+name = "uv-test"
+version = "0.1.0"
+dependencies = ["requests>=2.31"]
+
+[project.optional-dependencies]
+types = ["mypy>=1.0; python_version >= '3.9'"]
+
+[project.scripts]
+serve = "app.server:main"
+
+[dependency-groups]
+dev = ["Pytest>=8", { include-group = "lint" }]
+lint = ["ruff"]
+
+[tool.uv]
+package = true
+"#,
+    )?;
+
+    project.create_file(
+        "uv.lock",
+        r#"
+version = 1
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_pdm_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "pyproject.toml",
+        r#"
+[project]
+name = "pdm-test"
+version = "0.1.0"
+
+[tool.pdm.scripts]
+_.env_file = ".env"
+lint = "ruff check ."
+all = { composite = ["lint", "test"] }
+
+[tool.pdm.scripts.test]
+cmd = ["pytest", "tests"]
+help = "Run the tests"
+"#,
+    )?;
+
+    project.create_file("pdm.lock", "")?;
+
+    Ok(project)
+}
+
+pub fn create_hatch_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "pyproject.toml",
+        r#"
+[project]
+name = "hatch-test"
+version = "0.1.0"
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[tool.hatch.envs.default.scripts]
+test = "pytest {args:tests}"
+
+[tool.hatch.envs.lint.scripts]
+check = ["ruff check .", "mypy src"]
+"#,
+    )?;
+
+    project.create_file(
+        "hatch.toml",
+        r#"
+[envs.docs.scripts]
+build = "mkdocs build"
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_rye_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "pyproject.toml",
+        r#"
+[project]
+name = "rye-test"
+version = "0.1.0"
+
+[tool.rye]
+managed = true
+
+[tool.rye.scripts]
+fmt = "ruff format"
+test = ["pytest", "-x"]
+serve = { cmd = "flask run", env = { FLASK_DEBUG = "1" } }
+check = { chain = ["fmt", "test"] }
+
+[tool.hatch.metadata]
+allow-direct-references = true
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_tox_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "tox.ini",
+        r#"
+[tox]
+envlist =
+    py{311,312}-django{4,5},
+    lint
+
+[testenv]
+description = Run the test suite
+deps = pytest
+commands = pytest {posargs}
+
+[testenv:lint]
+description = Check code style
+commands = ruff check .
+
+[testenv:docs]
+commands =
+    sphinx-build -b html docs docs/_build
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_nox_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "noxfile.py",
+        r#"
+import nox
+
+
+@nox.session(python=["3.11", "3.12"])
+def tests(session):
+    """Run the test suite."""
+    session.install("pytest")
+    session.run("pytest")
+
+
+@nox.session(
+    name="lint",
+    reuse_venv=True,
+)
+def run_linters(session: nox.Session) -> None:
+    """
+    Lint using ruff.
+    """
+    session.run("ruff", "check", ".")
+
+
+@nox.session
+@nox.parametrize("builder", ["html"])
+def docs(session, builder):
+    session.run("sphinx-build", "-b", builder, "docs", "docs/_build")
+
+
+def helper():
+    pass
+"#,
+    )?;
+
+    Ok(project)
+}
+
 pub fn create_pip_project(dir: &PathBuf) -> Result<TestProject> {
     let project = TestProject { dir: dir.clone() };
 
@@ -175,6 +1257,135 @@ dev:
     Ok(project)
 }
 
+pub fn create_just_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "justfile",
+        r#"
+set shell := ["bash", "-c"]
+
+version := "1.0"
+
+alias b := build
+
+# Build the release binary
+build:
+    cargo build --release
+
+# Run the tests
+test filter="" +flags: build
+    cargo test {{filter}} {{flags}}
+
+fmt:
+    cargo fmt --all
+
+_helper:
+    echo helper
+
+[private]
+secret:
+    echo secret
+"#,
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_make_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "Makefile",
+        "CC := gcc
+PREFIX ?= /usr/local
+SOURCES = main.c \\
+\tutil.c
+
+.PHONY: all build test \\
+\tdocs.html lint
+
+all: build
+
+## Compile the program
+build: bin/app
+
+bin/app: $(SOURCES)
+\t$(CC) -o $@ $^
+
+test: build ## Run the test suite
+\t./run-tests.sh
+
+docs.html:
+\tpandoc README.md -o docs.html
+
+%.o: %.c
+\t$(CC) -c $<
+
+include rules.mk
+-include missing.mk
+
+release: VERSION = 1.0
+release:
+\t./scripts/release.sh $(VERSION)
+",
+    )?;
+
+    project.create_file(
+        "rules.mk",
+        "## Lint the sources
+lint:
+\tcppcheck .
+",
+    )?;
+
+    Ok(project)
+}
+
+pub fn create_task_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "Taskfile.yml",
+        r#"
+version: '3'
+
+tasks:
+  build:
+    desc: Build the app
+    cmds:
+      - go build -o bin/app .
+
+  serve:
+    aliases: [dev, s]
+    cmds:
+      - task: build
+      - ./bin/app
+
+  test:
+    summary: |
+      Runs the whole test suite.
+    cmds:
+      - cmd: go test ./...
+
+  setup:
+    internal: true
+    cmds:
+      - go mod download
+
+  lint: golangci-lint run
+"#,
+    )?;
+
+    Ok(project)
+}
+
 pub fn setup_test_projects() -> Result<tempfile::TempDir> {
     let temp_dir = tempfile::TempDir::new()?;
 
@@ -17,6 +17,8 @@ pub struct App<'a> {
     pub selected_script_state: ListState,
     pub show_emoji: bool,
     pub visual_to_script_index: Vec<Option<usize>>,
+    pub tool: Option<String>,
+    pub warnings: Vec<String>,
 }
 
 impl<'a> App<'a> {
@@ -39,6 +41,8 @@ impl<'a> App<'a> {
             selected_project_state: ListState::default(),
             show_emoji: settings.show_emoji,
             visual_to_script_index: Vec::new(),
+            tool: project.tool_description(),
+            warnings: warning_messages(project),
         };
 
         app.selected_script_state.select(Some(0));
@@ -146,6 +150,8 @@ impl<'a> App<'a> {
             .unwrap();
         self.visible_script_indices = (0..self.scripts.len()).collect();
         self.selected_script_state.select(Some(0));
+        self.tool = self.project.tool_description();
+        self.warnings = warning_messages(self.project);
     }
 
     pub fn group_scripts(&self) -> Vec<Vec<&Script>> {
@@ -156,3 +162,11 @@ impl<'a> App<'a> {
         name == "Current Directory"
     }
 }
+
+fn warning_messages(project: &Project) -> Vec<String> {
+    project
+        .warnings()
+        .into_iter()
+        .map(|warning| format!("{}. Run `psr doctor` for details.", warning.message))
+        .collect()
+}
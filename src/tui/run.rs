@@ -17,6 +17,7 @@ pub fn run_tui(project: &Project, settings: &Settings) -> Result<()> {
         .projects
         .iter()
         .filter_map(|(name, path)| Project::create(name, path))
+        .map(|project| project.with_priority(&settings.provider_priority))
         .collect::<Vec<Project>>();
     let mut project_owners_refs = project_owners.iter().map(|p| p).collect::<Vec<&Project>>();
 
@@ -28,6 +29,17 @@ pub fn run_tui(project: &Project, settings: &Settings) -> Result<()> {
         project_owners_refs.insert(0, project);
     }
 
+    // list workspace members after their workspace, so they can be browsed
+    // like projects
+    let members = project.members();
+    let project_index = project_owners_refs
+        .iter()
+        .position(|p| p.path.as_path() == project.path.as_path())
+        .unwrap_or(0);
+    for (i, member) in members.iter().enumerate() {
+        project_owners_refs.insert(project_index + 1 + i, member);
+    }
+
     let mut app = App::new(project, &project_owners_refs, settings.theme, settings)?;
     let backend = CrosstermBackend::new(stdout());
     let mut terminal = Terminal::new(backend)?;
@@ -40,7 +52,7 @@ pub fn run_tui(project: &Project, settings: &Settings) -> Result<()> {
             AppAction::Quit => break,
             AppAction::RunScript(script_name) => {
                 if let Some(script) = app.scripts.iter().find(|s| s.name == script_name) {
-                    let status_code = run_script(script)?;
+                    let status_code = run_script(app.project, script)?;
                     terminal.draw(|_| {})?;
                     if let Some(code) = status_code {
                         display_error_splash(&mut terminal, code)?;
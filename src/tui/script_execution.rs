@@ -4,18 +4,18 @@ use ratatui::{
   backend::CrosstermBackend, style::{Color, Modifier, Style}, text::{Span, Line}, widgets::{Block, Borders, Paragraph, Wrap}, Terminal
 };
 
-use crate::types::Script;
+use crate::types::{Project, Script};
 use super::utils::{restore_terminal, prepare_terminal, centered_rect};
 
-pub fn run_script(script: &Script) -> Result<Option<i32>> {
+pub fn run_script(project: &Project, script: &Script) -> Result<Option<i32>> {
     restore_terminal()?;
     let _guard = scopeguard::guard((), |_| {
         let _ = prepare_terminal();
     });
 
-    let status = std::process::Command::new("sh")
-        .arg("-c")
-        .arg(&script.command)
+    let status = project
+        .script_command(script)
+        .current_dir(&project.path)
         .status()?;
 
     println!("Press 'q' to quit or any other key to continue...");
@@ -111,15 +111,20 @@ fn draw_script_preview(
     area: Rect
 ) {
     if let Some(script) = app.get_selected_script() {
-        let preview = Paragraph::new(render_script_preview(script, app.theme, app.show_emoji))
+        let preview = Paragraph::new(render_script_preview(
+            script,
+            app.tool.as_deref(),
+            app.theme,
+            app.show_emoji,
+        ))
             .block(Block::default().title("Details").borders(Borders::ALL))
             .wrap(Wrap { trim: true });
         f.render_widget(preview, area);
     }
 }
 
-fn draw_help(f: &mut Frame, area: Rect) {
-    let help_text = vec![Line::from(vec![
+fn draw_help(f: &mut Frame, app: &App, area: Rect) {
+    let mut help_text = vec![Line::from(vec![
         Span::styled("Navigation: ", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw("↑/↓ Scripts, ←/→ Projects, "),
         Span::styled("Select: ", Style::default().add_modifier(Modifier::BOLD)),
@@ -127,6 +132,12 @@ fn draw_help(f: &mut Frame, area: Rect) {
         Span::styled("Quit: ", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw("q/Esc"),
     ])];
+    help_text.extend(app.warnings.iter().map(|warning| {
+        Line::from(Span::styled(
+            format!("Warning: {}", warning),
+            Style::default().fg(Color::Yellow),
+        ))
+    }));
     let help = Paragraph::new(help_text)
         .block(Block::default().title("Help").borders(Borders::ALL))
         .alignment(ratatui::layout::Alignment::Center);
@@ -144,8 +155,8 @@ fn draw_ui<W: std::io::Write>(
                 [
                     Constraint::Length(3),
                     Constraint::Min(3),
-                    Constraint::Length(5),
-                    Constraint::Length(3),
+                    Constraint::Length(8),
+                    Constraint::Length(3 + app.warnings.len() as u16),
                 ]
                 .as_ref(),
             )
@@ -154,7 +165,7 @@ fn draw_ui<W: std::io::Write>(
         draw_projects_list(f, app, chunks[0]);
         draw_scripts_list(f, app, chunks[1]);
         draw_script_preview(f, app, chunks[2]);
-        draw_help(f, chunks[3]);
+        draw_help(f, app, chunks[3]);
     })?;
 
     Ok(())
@@ -4,8 +4,13 @@ use ratatui::text::{Line, Span};
 use crate::types::Script;
 use crate::themes::Theme;
 
-pub fn render_script_preview(script: &Script, theme: Theme, show_emoji: bool) -> Vec<Line> {
-    vec![
+pub fn render_script_preview<'a>(
+    script: &'a Script,
+    tool: Option<&str>,
+    theme: Theme,
+    show_emoji: bool,
+) -> Vec<Line<'a>> {
+    let mut lines = vec![
         Line::from(vec![
             Span::styled("Name: ", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(format!(
@@ -41,5 +46,18 @@ pub fn render_script_preview(script: &Script, theme: Theme, show_emoji: bool) ->
                     .unwrap_or("No description available"),
             ),
         ]),
-    ]
+    ];
+    if let Some(tool) = tool {
+        lines.push(Line::from(vec![
+            Span::styled("Runs with: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(tool.to_string()),
+        ]));
+    }
+    if !script.depends_on.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Depends on: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(script.depends_on.join(", ")),
+        ]));
+    }
+    lines
 }
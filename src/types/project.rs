@@ -1,8 +1,10 @@
+use std::collections::HashSet;
 use std::path::Path;
 use std::path::PathBuf;
+use std::process::Command;
 
-use crate::package_managers::detect_package_manager_in_dir;
-use crate::package_managers::PackageManager;
+use crate::package_managers::detect_package_managers_in_dir;
+use crate::package_managers::{unparsable_config, PackageManager, Warning};
 use crate::types::Script;
 use anyhow::Result;
 
@@ -10,12 +12,126 @@ pub struct Project {
     #[allow(dead_code)]
     pub name: Option<String>,
     pub path: PathBuf,
-    pub package_manager: Box<dyn PackageManager>,
+    /// The providers of the project's ecosystems, in priority order. Most
+    /// projects have one; a Tauri app, for example, has both npm and cargo.
+    pub package_managers: Vec<Box<dyn PackageManager>>,
 }
 
 impl Project {
+    /// Returns the scripts of every provider. A name that several providers
+    /// define is prefixed with each one's tool, as in `npm:test` and
+    /// `cargo:test`, and kept as an alias, which resolves to the script of
+    /// the first provider.
     pub fn scripts(&self) -> Result<Vec<Script>> {
-        self.package_manager.find_scripts(&self.path)
+        Ok(self
+            .provider_scripts()?
+            .into_iter()
+            .map(|(_, _, listed)| listed)
+            .collect())
+    }
+
+    /// Returns the command that runs `script`, one of the project's scripts.
+    pub fn script_command(&self, script: &Script) -> Command {
+        if let [package_manager] = self.package_managers.as_slice() {
+            return package_manager.script_command(script);
+        }
+        let found = self.provider_scripts().ok().and_then(|scripts| {
+            scripts
+                .into_iter()
+                .find(|(_, _, listed)| listed.name == script.name)
+        });
+        match found {
+            Some((package_manager, original, _)) => package_manager.script_command(&original),
+            None => self.package_managers[0].script_command(script),
+        }
+    }
+
+    /// Returns each provider's scripts as the provider defines them and as
+    /// the project lists them.
+    /// A provider whose scripts can't be read, such as one with a malformed
+    /// configuration file, is left out and reported by `warnings`, unless
+    /// every provider fails.
+    fn provider_scripts(&self) -> Result<Vec<(&dyn PackageManager, Script, Script)>> {
+        let mut scripts = Vec::new();
+        let mut errors = Vec::new();
+        for (index, package_manager) in self.package_managers.iter().enumerate() {
+            match package_manager.find_scripts(&self.path) {
+                Ok(found) => scripts.extend(
+                    found
+                        .into_iter()
+                        .map(|script| (index, package_manager.as_ref(), script)),
+                ),
+                Err(error) => errors.push(error),
+            }
+        }
+        if errors.len() == self.package_managers.len() {
+            if let Some(error) = errors.into_iter().next() {
+                return Err(error);
+            }
+        }
+        if self.package_managers.len() == 1 {
+            return Ok(scripts
+                .into_iter()
+                .map(|(_, package_manager, script)| (package_manager, script.clone(), script))
+                .collect());
+        }
+
+        let is_shared = |name: &str| {
+            scripts
+                .iter()
+                .filter(|(_, _, script)| script.name == name)
+                .map(|(index, _, _)| index)
+                .collect::<HashSet<_>>()
+                .len()
+                > 1
+        };
+        let mut shortcuts = HashSet::new();
+        let listed: Vec<_> = scripts
+            .iter()
+            .map(|(_, package_manager, script)| {
+                let mut listed = script.clone();
+                if is_shared(&script.name) {
+                    listed.name = format!("{}:{}", package_manager.tool_name(), script.name);
+                    listed.aliases.push(script.name.clone());
+                }
+                // The first provider keeps a shortcut that several define
+                listed.shortcut = script.shortcut.filter(|c| shortcuts.insert(*c));
+                listed
+            })
+            .collect();
+        Ok(scripts
+            .into_iter()
+            .zip(listed)
+            .map(|((_, package_manager, script), listed)| (package_manager, script, listed))
+            .collect())
+    }
+
+    /// Returns the names of the tools that run the project's scripts, with
+    /// the versions the project pins, for display.
+    pub fn tool_description(&self) -> Option<String> {
+        let tools: Vec<String> = self
+            .package_managers
+            .iter()
+            .filter_map(|package_manager| package_manager.tool_description(&self.path))
+            .collect();
+        if tools.is_empty() {
+            None
+        } else {
+            Some(tools.join(", "))
+        }
+    }
+
+    /// Orders the providers by `priority`, a list of tool names such as
+    /// `["cargo", "npm"]`. Providers it doesn't name follow those it does,
+    /// in the order in which they were detected.
+    pub fn with_priority(mut self, priority: &[String]) -> Self {
+        self.package_managers.sort_by_key(|package_manager| {
+            priority
+                .iter()
+                .position(|tool| tool == package_manager.tool_name())
+                .unwrap_or(priority.len())
+        });
+        self
     }
 
     pub fn detect(path: &Path) -> Option<Project> {
@@ -25,15 +141,47 @@ impl Project {
     pub fn create(name: &str, path: &Path) -> Option<Project> {
         create_project(name, path)
     }
+
+    /// Returns problems with the project's configuration.
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut warnings: Vec<Warning> = self
+            .package_managers
+            .iter()
+            .flat_map(|package_manager| package_manager.warnings(&self.path))
+            .collect();
+        for package_manager in &self.package_managers {
+            if let Err(error) = package_manager.find_scripts(&self.path) {
+                let source = format!("the {} configuration", package_manager.tool_name());
+                warnings.push(unparsable_config(&source, &error));
+            }
+        }
+        warnings
+    }
+
+    /// Returns the member packages of the project, if it's a workspace.
+    pub fn members(&self) -> Vec<Project> {
+        self.package_managers
+            .iter()
+            .flat_map(|package_manager| package_manager.workspace_members(&self.path))
+            .map(|member| Project {
+                name: Some(member.name),
+                path: member.path,
+                package_managers: vec![member.package_manager],
+            })
+            .collect()
+    }
 }
 
-fn search_upwards_for_package_manager(dir: &Path) -> Option<(Box<dyn PackageManager>, PathBuf)> {
+fn search_upwards_for_package_managers(
+    dir: &Path,
+) -> Option<(Vec<Box<dyn PackageManager>>, PathBuf)> {
     let mut current_dir = dir;
     let home_dir = dirs::home_dir()?;
 
     while current_dir >= home_dir.as_path() {
-        if let Some(pm) = detect_package_manager_in_dir(current_dir) {
-            return Some((pm, current_dir.to_path_buf()));
+        let package_managers = detect_package_managers_in_dir(current_dir);
+        if !package_managers.is_empty() {
+            return Some((package_managers, current_dir.to_path_buf()));
         }
         current_dir = current_dir.parent()?;
     }
@@ -42,22 +190,164 @@ fn search_upwards_for_package_manager(dir: &Path) -> Option<(Box<dyn PackageMana
 }
 
 pub fn detect_project(dir: &Path) -> Option<Project> {
-    let (pm, path) = search_upwards_for_package_manager(dir)?;
+    let (package_managers, path) = search_upwards_for_package_managers(dir)?;
     Some(Project {
         name: Some(path.to_string_lossy().to_string()),
         path: path,
-        package_manager: pm,
+        package_managers,
     })
 }
 
 pub fn create_project(name: &str, path: &Path) -> Option<Project> {
-    if let Some(pm) = detect_package_manager_in_dir(path) {
-        Some(Project {
-            name: Some(name.to_string()),
-            path: path.to_path_buf(),
-            package_manager: pm,
-        })
-    } else {
-        None
+    let package_managers = detect_package_managers_in_dir(path);
+    if package_managers.is_empty() {
+        return None;
+    }
+    Some(Project {
+        name: Some(name.to_string()),
+        path: path.to_path_buf(),
+        package_managers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::project_dir_mocks::*;
+    use crate::types::find_synonym_script;
+
+    #[test]
+    fn test_polyglot_project() {
+        let temp_dir = create_polyglot_project(&std::env::temp_dir().join("polyglot")).unwrap();
+        let project = Project::create("app", &temp_dir.dir).unwrap();
+        let tools: Vec<&str> = project
+            .package_managers
+            .iter()
+            .map(|package_manager| package_manager.tool_name())
+            .collect();
+        assert_eq!(tools, ["npm", "cargo"]);
+
+        let scripts = project.scripts().unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+        assert!(names.contains(&"dev"));
+        assert!(names.contains(&"npm:test") && names.contains(&"cargo:test"));
+        assert!(names.contains(&"npm:build") && names.contains(&"cargo:build"));
+        assert!(!names.contains(&"test"));
+
+        // Shared names resolve to the first provider's script
+        assert_eq!(find_synonym_script(&scripts, "test").as_deref(), Some("npm:test"));
+        let script = scripts.iter().find(|s| s.name == "cargo:test").unwrap();
+        let command = project.script_command(script);
+        assert_eq!(command.get_program(), "sh");
+        assert_eq!(command.get_args().nth(1).unwrap(), "cargo test \"$@\"");
+        let script = scripts.iter().find(|s| s.name == "npm:test").unwrap();
+        let command = project.script_command(script);
+        assert_eq!(command.get_program(), "npm");
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["run", "test"]);
+
+        let project = project.with_priority(&["cargo".to_string()]);
+        let scripts = project.scripts().unwrap();
+        assert_eq!(find_synonym_script(&scripts, "test").as_deref(), Some("cargo:test"));
+        let shortcut = |name: &str| scripts.iter().find(|s| s.name == name).unwrap().shortcut;
+        assert_eq!(shortcut("cargo:test"), Some('t'));
+        assert_eq!(shortcut("npm:test"), None);
+    }
+
+    #[test]
+    fn test_justfile_next_to_cargo_toml() {
+        let dir = std::env::temp_dir().join("cargo-just-project");
+        create_cargo_project(&dir).unwrap();
+        let temp_dir = create_just_project(&dir).unwrap();
+        let project = Project::create("app", &temp_dir.dir).unwrap();
+        let tools: Vec<&str> = project
+            .package_managers
+            .iter()
+            .map(|package_manager| package_manager.tool_name())
+            .collect();
+        assert_eq!(tools, ["cargo", "just"]);
+
+        let scripts = project.scripts().unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+        assert!(names.contains(&"just:build") && names.contains(&"cargo:build"));
+        assert!(names.contains(&"just:test") && names.contains(&"cargo:test"));
+        let script = scripts.iter().find(|s| s.name == "just:build").unwrap();
+        let command = project.script_command(script);
+        assert_eq!(command.get_program(), "just");
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["build"]);
+    }
+
+    #[test]
+    fn test_makefile_next_to_go_mod() {
+        let temp_dir = create_go_project(&std::env::temp_dir().join("go-make-project")).unwrap();
+        let project = Project::create("app", &temp_dir.dir).unwrap();
+        let tools: Vec<&str> = project
+            .package_managers
+            .iter()
+            .map(|package_manager| package_manager.tool_name())
+            .collect();
+        assert_eq!(tools, ["go", "make"]);
+
+        let scripts = project.scripts().unwrap();
+        let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+        assert!(names.contains(&"all") && names.contains(&"dev"));
+        assert!(names.contains(&"make:build") && names.contains(&"go:build"));
+        assert!(!names.contains(&"make:all"));
+    }
+
+    #[test]
+    fn test_taskfile_next_to_go_mod() {
+        let dir = std::env::temp_dir().join("go-task-project");
+        create_go_module_project(&dir).unwrap();
+        let temp_dir = create_task_project(&dir).unwrap();
+        let project = Project::create("app", &temp_dir.dir).unwrap();
+        let tools: Vec<&str> = project
+            .package_managers
+            .iter()
+            .map(|package_manager| package_manager.tool_name())
+            .collect();
+        assert_eq!(tools, ["go", "task"]);
+
+        let scripts = project.scripts().unwrap();
+        assert_eq!(find_synonym_script(&scripts, "dev").as_deref(), Some("serve"));
+        let script = scripts.iter().find(|s| s.name == "task:build").unwrap();
+        let command = project.script_command(script);
+        assert_eq!(command.get_program(), "task");
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["build"]);
+    }
+
+    #[test]
+    fn test_tox_ini_next_to_pyproject_toml() {
+        let dir = std::env::temp_dir().join("poetry-tox-project");
+        create_poetry_project(&dir).unwrap();
+        let temp_dir = create_tox_project(&dir).unwrap();
+        let project = Project::create("app", &temp_dir.dir).unwrap();
+        let tools: Vec<&str> = project
+            .package_managers
+            .iter()
+            .map(|package_manager| package_manager.tool_name())
+            .collect();
+        assert_eq!(tools, ["poetry", "tox"]);
+
+        let scripts = project.scripts().unwrap();
+        let script = scripts.iter().find(|s| s.name == "py312-django5").unwrap();
+        assert_eq!(script.command, "tox -e py312-django5");
+        assert!(scripts.iter().any(|s| s.name == "docs"));
+    }
+
+    #[test]
+    fn test_provider_with_malformed_configuration() {
+        let temp_dir = create_npm_project(&std::env::temp_dir().join("npm-bad-taskfile")).unwrap();
+        temp_dir
+            .create_file("Taskfile.yml", "version: '3'\ntasks: [build\n")
+            .unwrap();
+        let project = Project::create("app", &temp_dir.dir).unwrap();
+        assert_eq!(project.package_managers.len(), 2);
+
+        let scripts = project.scripts().unwrap();
+        assert!(scripts.iter().any(|s| s.name == "start"));
+        assert!(scripts.iter().any(|s| s.name == "test"));
+        let warnings = project.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("the task configuration"));
     }
 }
@@ -6,6 +6,34 @@ pub struct Script {
     pub phase: Phase,
     pub script_type: ScriptType,
     pub shortcut: Option<char>,
+    pub aliases: Vec<String>,
+    /// The workspace member that defines the script, for scripts listed in
+    /// the workspace root.
+    pub package: Option<String>,
+    /// The tasks that run before this one, for task runners that declare them,
+    /// or the scripts it calls, such as Composer's `@script` references.
+    pub depends_on: Vec<String>,
+    /// The monorepo build system that runs the script, for Turborepo and Nx
+    /// tasks listed alongside a package's scripts.
+    pub pipeline: Option<Pipeline>,
+}
+
+/// A monorepo build system whose tasks run through its own CLI.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Pipeline {
+    Turbo,
+    Nx,
+}
+
+impl Pipeline {
+    /// Returns the name of the build system's CLI, which prefixes its tasks'
+    /// names.
+    pub fn tool_name(&self) -> &'static str {
+        match self {
+            Self::Turbo => "turbo",
+            Self::Nx => "nx",
+        }
+    }
 }
 
 impl Script {
@@ -23,9 +51,38 @@ impl Script {
             phase: script_type.map(|p| p.phase()).unwrap_or(Phase::Unknown),
             script_type: script_type.unwrap_or(ScriptType::from_script(name, command)),
             shortcut,
+            aliases: Vec::new(),
+            package: None,
+            depends_on: Vec::new(),
+            pipeline: None,
         }
     }
 
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    pub fn with_package(mut self, package: &str) -> Self {
+        self.package = Some(package.to_string());
+        self
+    }
+
+    pub fn with_depends_on(mut self, depends_on: Vec<String>) -> Self {
+        self.depends_on = depends_on;
+        self
+    }
+
+    pub fn with_pipeline(mut self, pipeline: Pipeline) -> Self {
+        self.pipeline = Some(pipeline);
+        self
+    }
+
+    /// Returns true if `name` is this script's name or one of its aliases.
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name || self.aliases.iter().any(|a| a == name)
+    }
+
     pub fn icon(&self) -> Option<&'static str> {
         self.script_type.icon()
     }
@@ -245,6 +302,11 @@ pub fn find_synonym_script(scripts: &[Script], name: &str) -> Option<String> {
         return Some(name.to_string());
     }
 
+    // Match on a script's own aliases, e.g. Taskfile `aliases`
+    if let Some(script) = scripts.iter().find(|s| s.is_named(name)) {
+        return Some(script.name.clone());
+    }
+
     // Check all ScriptTypes for synonyms
     for script_type in [
         ScriptType::Serve,
@@ -307,6 +369,10 @@ mod tests {
             script_type: ScriptType::Other,
             shortcut: None,
             phase: Phase::Development,
+            aliases: Vec::new(),
+            package: None,
+            depends_on: Vec::new(),
+            pipeline: None,
         }
     }
 
@@ -355,6 +421,16 @@ mod tests {
         assert_eq!(find_synonym_script(&scripts, "lint"), None);
     }
 
+    #[test]
+    fn test_find_synonym_script_alias() {
+        let scripts = vec![make_script("serve").with_aliases(vec!["dev".to_string()])];
+
+        assert_eq!(
+            find_synonym_script(&scripts, "dev"),
+            Some("serve".to_string())
+        );
+    }
+
     #[test]
     fn test_find_synonym_script_empty() {
         let empty_scripts: Vec<Script> = vec![];
@@ -2,7 +2,7 @@
 use anyhow::Result;
 use serde_json::json;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct TestProject {
     pub dir: PathBuf,
@@ -176,6 +176,51 @@ dev:
     Ok(project)
 }
 
+pub fn create_npm_workspace_project(dir: &Path) -> Result<TestProject> {
+    let project = TestProject {
+        dir: dir.to_path_buf(),
+    };
+
+    project.create_file(
+        "package.json",
+        &json!({
+            "name": "workspace-test",
+            "private": true,
+            "workspaces": ["packages/*"]
+        })
+        .to_string(),
+    )?;
+    project.create_file("package-lock.json", "{}")?;
+
+    project.create_file(
+        "packages/app/package.json",
+        &json!({
+            "name": "app",
+            "scripts": { "build": "false" },
+            "dependencies": { "lib": "*" }
+        })
+        .to_string(),
+    )?;
+    project.create_file(
+        "packages/docs/package.json",
+        &json!({
+            "name": "docs",
+            "scripts": { "serve": "true" }
+        })
+        .to_string(),
+    )?;
+    project.create_file(
+        "packages/lib/package.json",
+        &json!({
+            "name": "lib",
+            "scripts": { "build": "true" }
+        })
+        .to_string(),
+    )?;
+
+    Ok(project)
+}
+
 pub fn setup_test_projects() -> Result<tempfile::TempDir> {
     let temp_dir = tempfile::TempDir::new()?;
 
@@ -191,3 +191,32 @@ fn test_cli_options() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_all_packages() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let project = create_npm_workspace_project(&temp_dir.path().join("workspace"))?;
+
+    // `lib` builds before `app`, which depends on it; `docs` has no build
+    let output = Command::cargo_bin("psr")?
+        .current_dir(&project.dir)
+        .arg("--all-packages")
+        .arg("build")
+        .assert()
+        .failure()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output)?;
+
+    let lib = stdout.find("==> lib").expect("lib was built");
+    let app = stdout.find("==> app").expect("app was built");
+    assert!(lib < app);
+    assert!(!stdout.contains("==> docs"));
+    assert!(stdout.contains("lib      passed"));
+    assert!(stdout.contains("app      failed (exit code 1)"));
+    assert!(stdout.contains("1 passed, 1 failed"));
+
+    Ok(())
+}